@@ -1,17 +1,41 @@
-use std::f64::consts::PI;
-use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    iter::Sum,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
 
+#[derive(Clone, Copy, PartialEq)]
 pub enum AngleTypes {
     Deg(f64),
     Rad(f64),
+    Grad(f64),
+    Turn(f64),
 }
 
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 pub struct Angle {
     deg: f64,
     rad: f64,
 }
 
+impl Angle {
+    pub const ZERO: Angle = Angle { deg: 0.0, rad: 0.0 };
+    pub const RIGHT: Angle = Angle {
+        deg: 90.0,
+        rad: FRAC_PI_2,
+    };
+    pub const STRAIGHT: Angle = Angle {
+        deg: 180.0,
+        rad: PI,
+    };
+    pub const FULL: Angle = Angle {
+        deg: 360.0,
+        rad: TAU,
+    };
+}
+
 impl Angle {
     pub fn get_deg(&self) -> f64 {
         self.deg
@@ -20,6 +44,14 @@ impl Angle {
     pub fn get_rad(&self) -> f64 {
         self.rad
     }
+
+    pub fn get_grad(&self) -> f64 {
+        self.deg * 400.0 / 360.0
+    }
+
+    pub fn get_turn(&self) -> f64 {
+        self.deg / 360.0
+    }
 }
 
 impl Angle {
@@ -33,6 +65,8 @@ impl Angle {
                 deg: rad * 180.0 / PI,
                 rad,
             },
+            AngleTypes::Grad(grad) => Angle::new_grad(grad),
+            AngleTypes::Turn(turn) => Angle::new_turn(turn),
         }
     }
 
@@ -49,6 +83,274 @@ impl Angle {
             rad: deg * PI / 180.0,
         }
     }
+
+    pub fn new_grad(grad: f64) -> Angle {
+        Angle::new_deg(grad * 360.0 / 400.0)
+    }
+
+    pub fn new_turn(turn: f64) -> Angle {
+        Angle {
+            deg: turn * 360.0,
+            rad: turn * TAU,
+        }
+    }
+}
+
+impl Angle {
+    /// Returns the sine of the angle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::angles::Angle;
+    ///
+    /// let angle: Angle = Angle::new_deg(90.0);
+    ///
+    /// assert!((angle.sin() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn sin(&self) -> f64 {
+        self.rad.sin()
+    }
+
+    pub fn cos(&self) -> f64 {
+        self.rad.cos()
+    }
+
+    pub fn tan(&self) -> f64 {
+        self.rad.tan()
+    }
+
+    pub fn from_asin(val: f64) -> Angle {
+        Angle::new_rad(val.asin())
+    }
+
+    pub fn from_acos(val: f64) -> Angle {
+        Angle::new_rad(val.acos())
+    }
+
+    pub fn from_atan(val: f64) -> Angle {
+        Angle::new_rad(val.atan())
+    }
+
+    /// Creates an angle from the four-quadrant arctangent of `y / x`.
+    /// The result lies in `(-π, π]`.
+    pub fn from_atan2(y: f64, x: f64) -> Angle {
+        Angle::new_rad(y.atan2(x))
+    }
+
+    /// Returns the heading of the direction `(x, y)`, measured counter-clockwise from the positive x axis.
+    /// The result lies in `(-π, π]`, and the zero vector gives a zero angle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::angles::Angle;
+    ///
+    /// assert_eq!(Angle::from_vector2d(0.0, 1.0).get_deg(), 90.0);
+    /// ```
+    pub fn from_vector2d(x: f64, y: f64) -> Angle {
+        Angle::from_atan2(y, x)
+    }
+}
+
+impl Angle {
+    /// Returns the angle wrapped into `[0, 2π)`.
+    pub fn normalized(&self) -> Angle {
+        let rad: f64 = self.rad.rem_euclid(TAU);
+
+        // `rem_euclid` rounds tiny negative inputs up to exactly `TAU`.
+        if rad == TAU {
+            Angle::new_rad(0.0)
+        } else {
+            Angle::new_rad(rad)
+        }
+    }
+
+    /// Returns the angle wrapped into `(-π, π]`.
+    pub fn normalized_signed(&self) -> Angle {
+        let rad: f64 = self.rad.rem_euclid(TAU);
+
+        if rad > PI {
+            Angle::new_rad(rad - TAU)
+        } else {
+            Angle::new_rad(rad)
+        }
+    }
+
+    /// Creates an angle from degrees wrapped into `[0°, 360°)`.
+    /// The wrapping is done in degrees, so exact inputs such as `450°` give exactly `90°`.
+    pub fn wrap_deg(deg: f64) -> Angle {
+        let deg: f64 = deg.rem_euclid(360.0);
+
+        // `rem_euclid` rounds tiny negative inputs up to exactly `360.0`.
+        if deg == 360.0 {
+            Angle::new_deg(0.0)
+        } else {
+            Angle::new_deg(deg)
+        }
+    }
+
+    /// Creates an angle from degrees wrapped into `(-180°, 180°]`.
+    pub fn wrap_deg_signed(deg: f64) -> Angle {
+        let deg: f64 = deg.rem_euclid(360.0);
+
+        if deg > 180.0 {
+            Angle::new_deg(deg - 360.0)
+        } else {
+            Angle::new_deg(deg)
+        }
+    }
+
+    /// Returns the signed rotation in `(-π, π]` taking `self` to `other` along the shortest arc,
+    /// positive for counter-clockwise. From 350° to 10° this is +20°, not -340°.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::angles::Angle;
+    ///
+    /// let delta: Angle = Angle::new_deg(350.0).shortest_to(&Angle::new_deg(10.0));
+    ///
+    /// assert!((delta.get_deg() - 20.0).abs() < 1e-9);
+    /// ```
+    pub fn shortest_to(&self, other: &Angle) -> Angle {
+        Angle::new_rad(other.rad - self.rad).normalized_signed()
+    }
+
+    /// Interpolates along the shortest arc from `self` to `other`.
+    /// - `t = 0.0` gives `self` and `t = 1.0` gives `other` (up to a full turn).
+    /// - The result is wrapped into `(-π, π]`.
+    pub fn slerp(&self, other: &Angle, t: f64) -> Angle {
+        let diff: f64 = self.shortest_to(other).rad;
+
+        Angle::new_rad(self.rad + diff * t).normalized_signed()
+    }
+}
+
+impl Angle {
+    /// Returns `90° - self`.
+    /// Angles greater than 90° yield a negative complement.
+    pub fn complement(&self) -> Angle {
+        Angle::new_deg(90.0 - self.deg)
+    }
+
+    /// Returns `180° - self`.
+    /// Angles greater than 180° yield a negative supplement.
+    pub fn supplement(&self) -> Angle {
+        Angle::new_deg(180.0 - self.deg)
+    }
+}
+
+impl Angle {
+    /// Splits the angle into whole degrees, arcminutes and arcseconds.
+    /// - The sign is carried on the degree component.
+    /// - For angles in `(-1°, 0°)` the degree is zero, so the sign moves to the seconds
+    ///   (which may be `-0.0`); [`Angle::from_dms`] reads it back from there.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::angles::Angle;
+    ///
+    /// let (deg, min, sec) = Angle::new_deg(-12.5).to_dms();
+    ///
+    /// assert_eq!((deg, min), (-12, 30));
+    /// assert!(sec.abs() < 1e-9);
+    /// ```
+    pub fn to_dms(&self) -> (i32, u32, f64) {
+        let total_seconds: f64 = self.deg.abs() * 3600.0;
+        let degrees: f64 = (total_seconds / 3600.0).floor();
+        let minutes: f64 = ((total_seconds - degrees * 3600.0) / 60.0).floor();
+        let seconds: f64 = total_seconds - degrees * 3600.0 - minutes * 60.0;
+
+        match (self.deg < 0.0, degrees as i32) {
+            (true, 0) => (0, minutes as u32, -seconds),
+            (true, degrees) => (-degrees, minutes as u32, seconds),
+            (false, degrees) => (degrees, minutes as u32, seconds),
+        }
+    }
+
+    /// Creates an angle from degrees, arcminutes and arcseconds, using the sign convention of
+    /// [`Angle::to_dms`]: the angle is negative if `degrees` is negative, or if `degrees` is zero
+    /// and `seconds` is negative (including `-0.0`).
+    pub fn from_dms(degrees: i32, minutes: u32, seconds: f64) -> Angle {
+        let magnitude: f64 =
+            degrees.unsigned_abs() as f64 + minutes as f64 / 60.0 + seconds.abs() / 3600.0;
+
+        if degrees < 0 || (degrees == 0 && seconds.is_sign_negative()) {
+            Angle::new_deg(-magnitude)
+        } else {
+            Angle::new_deg(magnitude)
+        }
+    }
+}
+
+impl Angle {
+    /// Adds `delta` in place without wrapping, so the angle can grow past a full turn.
+    /// Useful for odometry, where the number of completed revolutions matters.
+    pub fn accumulate(&mut self, delta: Angle) {
+        self.deg += delta.deg;
+        self.rad += delta.rad;
+    }
+
+    /// Returns the total rotation in turns, including completed revolutions.
+    /// Negative for clockwise accumulation.
+    pub fn total_turns(&self) -> f64 {
+        self.get_turn()
+    }
+}
+
+impl Angle {
+    /// Returns true if the two angles differ by at most `eps_rad` radians.
+    /// No wrapping is applied, so `0°` and `360°` are not considered equal.
+    pub fn approx_eq(&self, other: &Angle, eps_rad: f64) -> bool {
+        (self.rad - other.rad).abs() <= eps_rad
+    }
+
+    /// Compares the angles by their position on the circle, i.e. their [`Angle::normalized`]
+    /// value in `[0, 2π)`, so `370°` sorts like `10°`.
+    /// - Positions are snapped to a `1e-9` radian grid first, so equivalent angles built
+    ///   differently compare `Equal` despite rounding, and values just below `2π` equal `0`.
+    /// - Snapping keeps the ordering total, which makes it safe to pass to `sort_by`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use vectra::angles::Angle;
+    ///
+    /// assert_eq!(Angle::new_deg(370.0).cmp_normalized(&Angle::new_deg(10.0)), Ordering::Equal);
+    /// assert_eq!(Angle::new_deg(-10.0).cmp_normalized(&Angle::new_deg(20.0)), Ordering::Greater);
+    /// ```
+    pub fn cmp_normalized(&self, other: &Angle) -> Ordering {
+        const STEP: f64 = 1e-9;
+
+        let position = |angle: &Angle| {
+            let steps: i64 = (angle.normalized().rad / STEP).round() as i64;
+
+            if steps >= (TAU / STEP).round() as i64 {
+                0
+            } else {
+                steps
+            }
+        };
+
+        position(self).cmp(&position(other))
+    }
+}
+
+/// Compares the radian values using exact float equality.
+/// Prefer [`Angle::approx_eq`] for values produced by arithmetic.
+impl PartialEq for Angle {
+    fn eq(&self, other: &Self) -> bool {
+        self.rad == other.rad
+    }
+}
+
+impl PartialOrd for Angle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.rad.partial_cmp(&other.rad)
+    }
 }
 
 impl Debug for Angle {
@@ -59,6 +361,88 @@ impl Debug for Angle {
 
 impl Display for Angle {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{}deg", self.deg)
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}deg", precision, self.deg),
+            None => write!(f, "{}deg", self.deg),
+        }
+    }
+}
+
+struct RadDisplay<'a>(&'a Angle);
+
+impl Display for RadDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}rad", precision, self.0.rad),
+            None => write!(f, "{}rad", self.0.rad),
+        }
+    }
+}
+
+impl Angle {
+    /// Returns a wrapper that displays the angle in radians.
+    /// Like the `Display` impl for `Angle`, the formatter's precision is respected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::angles::Angle;
+    ///
+    /// let angle: Angle = Angle::new_rad(1.5);
+    ///
+    /// assert_eq!(format!("{}", angle.display_rad()), "1.5rad");
+    /// assert_eq!(format!("{:.3}", angle.display_rad()), "1.500rad");
+    /// ```
+    pub fn display_rad(&self) -> impl Display + '_ {
+        RadDisplay(self)
+    }
+}
+
+impl Add for Angle {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Angle::new_rad(self.rad + other.rad)
+    }
+}
+
+impl Sub for Angle {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Angle::new_rad(self.rad - other.rad)
+    }
+}
+
+impl Neg for Angle {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Angle {
+            deg: -self.deg,
+            rad: -self.rad,
+        }
+    }
+}
+
+impl Mul<f64> for Angle {
+    type Output = Self;
+
+    fn mul(self, scalar: f64) -> Self {
+        Angle::new_rad(self.rad * scalar)
+    }
+}
+
+impl Div<f64> for Angle {
+    type Output = Self;
+
+    fn div(self, scalar: f64) -> Self {
+        Angle::new_rad(self.rad / scalar)
+    }
+}
+
+impl Sum for Angle {
+    fn sum<I: Iterator<Item = Angle>>(iter: I) -> Angle {
+        Angle::new_rad(iter.map(|angle| angle.rad).sum())
     }
 }
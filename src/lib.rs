@@ -6,6 +6,7 @@ pub mod traits;
 // module!(pub polynomial, pub nums);
 
 pub mod angles;
+pub mod matrix;
 pub mod miscs;
 pub mod nums;
 pub mod polynomial;
@@ -7,26 +7,123 @@ macro_rules! _test {
 
 #[macro_export]
 macro_rules! bundle_traits {
-    ( $trait_name:ident, $($traits:path),+ ) => {
-        trait $trait_name: $($traits +)+ {}
-        impl<T: $($traits +)+> $trait_name for T {}
+    ( $trait_name:ident, $($traits:path),+ $(where $($bounds:tt)+)? ) => {
+        trait $trait_name: $($traits +)+ $(where $($bounds)+)? {}
+        impl<T: $($traits +)+> $trait_name for T $(where $($bounds)+)? {}
     };
 
-    ( pub $trait_name:ident, $($traits:path),+ ) => {
-        pub trait $trait_name: $($traits +)+ {}
-        impl<T: $($traits +)+> $trait_name for T {}
+    ( pub $trait_name:ident, $($traits:path),+ $(where $($bounds:tt)+)? ) => {
+        pub trait $trait_name: $($traits +)+ $(where $($bounds)+)? {}
+        impl<T: $($traits +)+> $trait_name for T $(where $($bounds)+)? {}
+    };
+
+    ( $trait_name:ident < $($generics:tt),+ >, $($traits:path),+ $(where $($bounds:tt)+)? ) => {
+        trait $trait_name<$($generics),+>: $($traits +)+ $(where $($bounds)+)? {}
+        impl<$($generics,)+ __Bundled: $($traits +)+> $trait_name<$($generics),+> for __Bundled
+            $(where $($bounds)+)? {}
+    };
+
+    ( pub $trait_name:ident < $($generics:tt),+ >, $($traits:path),+ $(where $($bounds:tt)+)? ) => {
+        pub trait $trait_name<$($generics),+>: $($traits +)+ $(where $($bounds)+)? {}
+        impl<$($generics,)+ __Bundled: $($traits +)+> $trait_name<$($generics),+> for __Bundled
+            $(where $($bounds)+)? {}
     };
 }
 
+/// Evaluates the given block and measures how long it took.
+/// - `time_it!(block)` returns a [`Timed`](crate::miscs::timing::Timed) holding the value and the elapsed time.
+/// - `time_it!(@tuple block)` returns the older `(value, elapsed)` tuple.
+///
+/// # Examples
+///
+/// ```rust
+/// use vectra::time_it;
+///
+/// let sorted = time_it!({
+///     let mut values = vec![5, 3, 1, 4, 2];
+///     values.sort();
+///     values
+/// });
+///
+/// assert_eq!(*sorted, vec![1, 2, 3, 4, 5]);
+/// assert_eq!(sorted.len(), 5);
+/// println!("sorted in {:?}", sorted.elapsed);
+///
+/// let (value, _elapsed) = time_it!(@tuple { 1 + 1 });
+/// assert_eq!(value, 2);
+/// ```
 #[macro_export]
 macro_rules! time_it {
-    ( $token:tt ) => {{
+    ( @tuple $token:tt ) => {{
         let time = std::time::Instant::now();
 
         let result = $token;
 
         (result, time.elapsed())
     }};
+
+    ( $token:tt ) => {{
+        let time = std::time::Instant::now();
+
+        let result = $token;
+
+        $crate::miscs::timing::Timed {
+            value: result,
+            elapsed: time.elapsed(),
+        }
+    }};
+}
+
+/// Builds a [`Vector3D`](crate::vectors::vector3d::Vector3D) from exactly three components.
+///
+/// # Examples
+///
+/// ```rust
+/// use vectra::vector3d;
+/// use vectra::vectors::vector3d::Vector3D;
+///
+/// let v: Vector3D<f64> = vector3d![1.0, 2.0, 3.0];
+///
+/// assert_eq!(v.to_array(), [1.0, 2.0, 3.0]);
+/// ```
+///
+/// Any other number of components is rejected at compile time:
+///
+/// ```compile_fail
+/// use vectra::vector3d;
+///
+/// let v = vector3d![1.0, 2.0];
+/// ```
+#[macro_export]
+macro_rules! vector3d {
+    ( $x:expr, $y:expr, $z:expr $(,)? ) => {
+        $crate::vectors::vector3d::Vector3D::new($x, $y, $z)
+    };
+}
+
+/// Implements [`Numeric`](crate::traits::Numeric) for one or more types.
+/// - `impl_numeric!(i32, u8, f64)` registers primitive types, converting with `as f64`.
+/// - `impl_numeric!(Meters => |value: &Meters| value.0)` registers a custom type with the given conversion.
+#[macro_export]
+macro_rules! impl_numeric {
+    ( $type:ty => $to_f64:expr ) => {
+        impl $crate::traits::Numeric for $type {
+            fn to_f64(&self) -> f64 {
+                ($to_f64)(self)
+            }
+        }
+    };
+
+    ( $($type:ty),+ $(,)? ) => {
+        $(
+            impl $crate::traits::Numeric for $type {
+                #[allow(clippy::unnecessary_cast)]
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )+
+    };
 }
 
 #[macro_export]
@@ -0,0 +1,157 @@
+use super::super::vectors::vector3d::Vector3D;
+
+use num::{One, Zero};
+use std::ops::{Add, Mul, Sub};
+
+/// A 3×3 matrix stored row-major, acting on [`Vector3D`] column vectors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Matrix3<T> {
+    pub rows: [[T; 3]; 3],
+}
+
+impl<T> Matrix3<T> {
+    pub fn from_rows(rows: [[T; 3]; 3]) -> Self {
+        Self { rows }
+    }
+
+    pub fn identity() -> Self
+    where
+        T: Zero + One,
+    {
+        Self {
+            rows: [
+                [T::one(), T::zero(), T::zero()],
+                [T::zero(), T::one(), T::zero()],
+                [T::zero(), T::zero(), T::one()],
+            ],
+        }
+    }
+
+    /// Returns the matrix–vector product `M · v`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::matrix::mat3::Matrix3;
+    /// use vectra::vectors::vector3d::Vector3D;
+    ///
+    /// let m: Matrix3<i32> = Matrix3::from_rows([[1, 2, 0], [0, 1, 0], [0, 0, 3]]);
+    ///
+    /// assert_eq!(m.mul_vector(&Vector3D::new(1, 1, 1)).to_array(), [3, 1, 3]);
+    /// ```
+    pub fn mul_vector(&self, v: &Vector3D<T>) -> Vector3D<T>
+    where
+        T: Clone + Add<Output = T> + Mul<Output = T>,
+    {
+        let row = |r: &[T; 3]| {
+            r[0].clone() * v.x.clone() + r[1].clone() * v.y.clone() + r[2].clone() * v.z.clone()
+        };
+
+        Vector3D::new(row(&self.rows[0]), row(&self.rows[1]), row(&self.rows[2]))
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            rows: [0, 1, 2].map(|i| [0, 1, 2].map(|j| self.rows[j][i].clone())),
+        }
+    }
+
+    /// Returns the determinant, expanded along the first row.
+    pub fn determinant(&self) -> T
+    where
+        T: Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        let m = |i: usize, j: usize| self.rows[i][j].clone();
+
+        m(0, 0) * (m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1))
+            - m(0, 1) * (m(1, 0) * m(2, 2) - m(1, 2) * m(2, 0))
+            + m(0, 2) * (m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0))
+    }
+}
+
+impl<T> Matrix3<T>
+where
+    T: Clone + Into<f64>,
+{
+    /// Returns the inverse as `adj(M) / det(M)`, or `None` if the determinant is zero or not finite.
+    /// The size of the determinant says nothing about conditioning, so a scaled but invertible
+    /// matrix such as `diag(1e-10, 1e-10, 1)` still has an inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::matrix::mat3::Matrix3;
+    ///
+    /// let m: Matrix3<i32> = Matrix3::from_rows([[2, 0, 0], [0, 4, 0], [0, 0, 1]]);
+    ///
+    /// assert_eq!(
+    ///     m.inverse().unwrap().rows,
+    ///     [[0.5, 0.0, 0.0], [0.0, 0.25, 0.0], [0.0, 0.0, 1.0]]
+    /// );
+    /// assert!(Matrix3::from_rows([[1, 2, 3], [2, 4, 6], [0, 0, 1]]).inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Matrix3<f64>> {
+        let m: [[f64; 3]; 3] = self.rows.clone().map(|row| row.map(Into::into));
+        let m: Matrix3<f64> = Matrix3::from_rows(m);
+
+        let det: f64 = m.determinant();
+
+        if !det.is_finite() || det == 0.0 {
+            return None;
+        }
+
+        let cofactor = |i: usize, j: usize| {
+            let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+            let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+
+            m.rows[r0][c0] * m.rows[r1][c1] - m.rows[r0][c1] * m.rows[r1][c0]
+        };
+
+        // The adjugate is the transposed cofactor matrix.
+        Some(Matrix3::from_rows(
+            [0, 1, 2].map(|i| [0, 1, 2].map(|j| cofactor(j, i) / det)),
+        ))
+    }
+
+    /// Solves `M · x = rhs` for `x`, or returns `None` if the matrix is singular
+    /// (in the sense of [`Matrix3::inverse`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::matrix::mat3::Matrix3;
+    /// use vectra::vectors::vector3d::Vector3D;
+    ///
+    /// let m: Matrix3<f64> = Matrix3::from_rows([[2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [0.0, 0.0, 1.0]]);
+    ///
+    /// let x: Vector3D<f64> = m.solve(&Vector3D::new(1.0, 1.0, 1.0)).unwrap();
+    ///
+    /// assert_eq!(x.to_array(), [0.5, 0.25, 1.0]);
+    /// ```
+    pub fn solve(&self, rhs: &Vector3D<f64>) -> Option<Vector3D<f64>> {
+        Some(self.inverse()?.mul_vector(rhs))
+    }
+}
+
+impl<T> Mul for Matrix3<T>
+where
+    T: Clone + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    /// Composes two transforms: `(A * B) · v` applies `B` first, then `A`.
+    fn mul(self, rhs: Self) -> Self {
+        let entry = |i: usize, j: usize| {
+            self.rows[i][0].clone() * rhs.rows[0][j].clone()
+                + self.rows[i][1].clone() * rhs.rows[1][j].clone()
+                + self.rows[i][2].clone() * rhs.rows[2][j].clone()
+        };
+
+        Self {
+            rows: [0, 1, 2].map(|i| [0, 1, 2].map(|j| entry(i, j))),
+        }
+    }
+}
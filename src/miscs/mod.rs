@@ -1,4 +1,6 @@
 // pub mod dimensions;
+pub mod quantity;
+pub mod timing;
 pub mod units;
 // pub mod constants;
 // pub mod functions;
@@ -0,0 +1,143 @@
+use super::units::Unit;
+
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    ops::{Add, Div, Mul, Sub},
+};
+
+pub struct Quantity<T> {
+    pub value: T,
+    pub unit: Unit,
+}
+
+/// Returned when adding or subtracting quantities whose dimensions differ.
+#[derive(Debug)]
+pub struct UnitMismatchError {
+    pub lhs: Unit,
+    pub rhs: Unit,
+}
+
+impl Display for UnitMismatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "unit mismatch: {} and {}", self.lhs, self.rhs)
+    }
+}
+
+impl Error for UnitMismatchError {}
+
+impl<T> Quantity<T> {
+    pub fn new(value: T, unit: Unit) -> Self {
+        Self { value, unit }
+    }
+}
+
+impl<T> Add for Quantity<T>
+where
+    T: Add<Output = T> + Mul<f64, Output = T>,
+{
+    type Output = Result<Self, UnitMismatchError>;
+
+    /// Adds two quantities.
+    /// - Both operands must have the same dimension, otherwise an error is returned.
+    /// - The right operand is rescaled to the prefixes of the left one, so `1 km + 500 m` is
+    ///   `1.5 km`.
+    fn add(self, other: Self) -> Self::Output {
+        if !self.unit.same_dimension(&other.unit) {
+            return Err(UnitMismatchError {
+                lhs: self.unit,
+                rhs: other.unit,
+            });
+        }
+
+        let scale: f64 = other.unit.to_base_factor() / self.unit.to_base_factor();
+
+        Ok(Self {
+            value: self.value + other.value * scale,
+            unit: self.unit,
+        })
+    }
+}
+
+impl<T> Sub for Quantity<T>
+where
+    T: Sub<Output = T> + Mul<f64, Output = T>,
+{
+    type Output = Result<Self, UnitMismatchError>;
+
+    /// Subtracts one quantity from another.
+    /// - Both operands must have the same dimension, otherwise an error is returned.
+    /// - The right operand is rescaled to the prefixes of the left one, so `1 km - 500 m` is
+    ///   `0.5 km`.
+    fn sub(self, other: Self) -> Self::Output {
+        if !self.unit.same_dimension(&other.unit) {
+            return Err(UnitMismatchError {
+                lhs: self.unit,
+                rhs: other.unit,
+            });
+        }
+
+        let scale: f64 = other.unit.to_base_factor() / self.unit.to_base_factor();
+
+        Ok(Self {
+            value: self.value - other.value * scale,
+            unit: self.unit,
+        })
+    }
+}
+
+impl<T> Mul for Quantity<T>
+where
+    T: Mul<Output = T> + Mul<f64, Output = T>,
+{
+    type Output = Self;
+
+    /// Multiplies the values and combines the units.
+    /// Any prefix scale the combined unit cannot express is folded into the value,
+    /// so `1 km / 1 m` is `1000`.
+    fn mul(self, other: Self) -> Self {
+        let (unit, factor) = self.unit.mul_scaled(other.unit);
+
+        Self {
+            value: self.value * other.value * factor,
+            unit,
+        }
+    }
+}
+
+impl<T> Div for Quantity<T>
+where
+    T: Div<Output = T> + Mul<f64, Output = T>,
+{
+    type Output = Self;
+
+    /// Divides the values and combines the units.
+    /// Any prefix scale the combined unit cannot express is folded into the value,
+    /// so `1 km / 1 m` is `1000`.
+    fn div(self, other: Self) -> Self {
+        let (unit, factor) = self.unit.div_scaled(other.unit);
+
+        Self {
+            value: self.value / other.value * factor,
+            unit,
+        }
+    }
+}
+
+impl<T> Debug for Quantity<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?} {}", self.value, self.unit)
+    }
+}
+
+impl<T> Display for Quantity<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} {}", self.value, self.unit)
+    }
+}
@@ -0,0 +1,31 @@
+use std::{
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+/// A value paired with the time it took to compute, as returned by `time_it!`.
+#[derive(Debug)]
+pub struct Timed<T> {
+    pub value: T,
+    pub elapsed: Duration,
+}
+
+impl<T> Timed<T> {
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Timed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Timed<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
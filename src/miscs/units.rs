@@ -1,3 +1,13 @@
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    ops::{Div, Mul},
+    str::FromStr,
+};
+
+const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+#[derive(Clone, Copy, PartialEq)]
 pub enum BaseUnit {
     Length,
     Mass,
@@ -22,7 +32,7 @@ impl BaseUnit {
     }
 }
 
-#[derive(PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 pub enum UnitPrefix {
     Yotta = 24,
     Zetta = 21,
@@ -73,14 +83,58 @@ impl UnitPrefix {
             UnitPrefix::Yocto => "y",
         }
     }
+
+    /// Returns the scale factor of the prefix, e.g. `1000.0` for kilo.
+    pub fn factor(&self) -> f64 {
+        10f64.powi(*self as i32)
+    }
+
+    pub fn from_exponent(exponent: i32) -> Option<UnitPrefix> {
+        match exponent {
+            24 => Some(UnitPrefix::Yotta),
+            21 => Some(UnitPrefix::Zetta),
+            18 => Some(UnitPrefix::Exa),
+            15 => Some(UnitPrefix::Peta),
+            12 => Some(UnitPrefix::Tera),
+            9 => Some(UnitPrefix::Giga),
+            6 => Some(UnitPrefix::Mega),
+            3 => Some(UnitPrefix::Kilo),
+            2 => Some(UnitPrefix::Hecto),
+            1 => Some(UnitPrefix::Deca),
+            0 => Some(UnitPrefix::None),
+            -1 => Some(UnitPrefix::Deci),
+            -2 => Some(UnitPrefix::Centi),
+            -3 => Some(UnitPrefix::Milli),
+            -6 => Some(UnitPrefix::Micro),
+            -9 => Some(UnitPrefix::Nano),
+            -12 => Some(UnitPrefix::Pico),
+            -15 => Some(UnitPrefix::Femto),
+            -18 => Some(UnitPrefix::Atto),
+            -21 => Some(UnitPrefix::Zepto),
+            -24 => Some(UnitPrefix::Yocto),
+            _ => None,
+        }
+    }
 }
 
+#[derive(Clone, Copy)]
 pub struct DimensionalUnit {
     base: BaseUnit,
     prefix: UnitPrefix,
     power: i8,
 }
 
+impl DimensionalUnit {
+    pub fn new(base: BaseUnit, prefix: UnitPrefix, power: i8) -> Self {
+        DimensionalUnit {
+            base,
+            prefix,
+            power,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
 pub struct Unit {
     values: [DimensionalUnit; 7],
 }
@@ -156,18 +210,394 @@ impl Unit {
         }
     }
 
+    /// Creates an unprefixed unit from the powers of the seven base dimensions,
+    /// ordered as L, M, T, I, Θ, N, J.
+    pub fn from_powers(powers: [i8; 7]) -> Self {
+        let mut unit: Unit = Unit::default();
+
+        for (dimension, power) in unit.values.iter_mut().zip(powers) {
+            dimension.power = power;
+        }
+
+        unit
+    }
+
+    /// Returns the powers of the seven base dimensions, ordered as L, M, T, I, Θ, N, J.
+    pub fn powers(&self) -> [i8; 7] {
+        let mut powers: [i8; 7] = [0; 7];
+
+        for (power, dimension) in powers.iter_mut().zip(self.values.iter()) {
+            *power = dimension.power;
+        }
+
+        powers
+    }
+
+    /// Sets the prefix used for the given base dimension.
+    pub fn set_prefix(&mut self, base: BaseUnit, prefix: UnitPrefix) {
+        self.values[base as usize].prefix = prefix;
+    }
+
+    /// Returns true if both units have the same power on every base dimension,
+    /// regardless of their prefixes.
+    pub fn same_dimension(&self, other: &Unit) -> bool {
+        self.powers() == other.powers()
+    }
+
+    /// Returns the factor converting a value in this unit to unprefixed base SI units,
+    /// i.e. `10^(Σ prefix exponent × power)`.
+    /// For `km²` the factor is `10⁶`, for `ms⁻¹` it is `10³`.
+    pub fn to_base_factor(&self) -> f64 {
+        let exponent: i32 = self
+            .values
+            .iter()
+            .map(|dimension| dimension.prefix as i32 * dimension.power as i32)
+            .sum();
+
+        10f64.powi(exponent)
+    }
+
+    /// Raises the unit to an integer power by multiplying every dimension's power by `exp`.
+    /// Prefixes are kept, so `km.pow(2)` is `km²`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any resulting power does not fit in an `i8`.
+    pub fn pow(&self, exp: i8) -> Unit {
+        let mut result: Unit = *self;
+
+        for dimension in result.values.iter_mut() {
+            dimension.power = dimension
+                .power
+                .checked_mul(exp)
+                .expect("unit power overflowed i8");
+        }
+
+        result
+    }
+
+    /// Multiplies two units, returning the product together with the scale its prefixes could
+    /// not absorb: `a` in `self` times `b` in `rhs` equals `a * b * factor` in the returned unit.
+    /// For `km · m` the result is `km²` with a factor of `10⁻³`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any resulting power does not fit in an `i8`.
+    pub fn mul_scaled(self, rhs: Unit) -> (Unit, f64) {
+        let (unit, exponent) = self.combine(rhs, 1);
+
+        (unit, 10f64.powi(exponent))
+    }
+
+    /// Divides two units, returning the quotient together with the scale its prefixes could
+    /// not absorb: `a` in `self` over `b` in `rhs` equals `a / b * factor` in the returned unit.
+    /// For `km / m` the result is dimensionless with a factor of `10³`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any resulting power does not fit in an `i8`.
+    pub fn div_scaled(self, rhs: Unit) -> (Unit, f64) {
+        let (unit, exponent) = self.combine(rhs, -1);
+
+        (unit, 10f64.powi(exponent))
+    }
+
+    /// Combines two units dimension by dimension, adding `sign * rhs.power` to each power.
+    /// - The prefix scales of both operands are multiplied together.
+    /// - If the combined scale cannot be expressed as a single prefix, the existing prefix is kept.
+    /// - A dimension whose power cancels out loses its prefix.
+    ///
+    /// Also returns the power of ten that the chosen prefixes leave unaccounted for.
+    fn combine(mut self, rhs: Unit, sign: i8) -> (Unit, i32) {
+        let mut dropped: i32 = 0;
+
+        for (lhs, rhs) in self.values.iter_mut().zip(rhs.values) {
+            let power: i8 = rhs
+                .power
+                .checked_mul(sign)
+                .and_then(|rhs_power| lhs.power.checked_add(rhs_power))
+                .expect("unit power overflowed i8");
+            let scale: i32 = lhs.prefix as i32 * lhs.power as i32
+                + sign as i32 * rhs.power as i32 * rhs.prefix as i32;
+            let fallback: UnitPrefix = if lhs.power != 0 {
+                lhs.prefix
+            } else {
+                rhs.prefix
+            };
+
+            lhs.prefix = match power {
+                0 => UnitPrefix::None,
+                _ if scale % power as i32 == 0 => {
+                    UnitPrefix::from_exponent(scale / power as i32).unwrap_or(fallback)
+                }
+                _ => fallback,
+            };
+            lhs.power = power;
+            dropped += scale - lhs.prefix as i32 * power as i32;
+        }
+
+        (self, dropped)
+    }
+
     pub fn print(&self) {
-        let sup = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+        print!("{}", self);
+    }
+}
 
-        for i in 0..7 {
-            let mut symbol = self.values[i].base.get_symbol().to_string();
-            if self.values[i].prefix != UnitPrefix::None {
-                symbol.push_str(self.values[i].prefix.get_symbol());
+/// Rescales `value` into engineering notation by choosing an SI prefix for the unit's dominant
+/// dimension (the one with the largest absolute power), e.g. `1500 m` becomes `1.5 km`.
+/// - Only prefixes that are powers of `10³` are chosen, clamped to the yocto–yotta range.
+/// - For a dominant power of `±1` the returned value lies in `[1, 1000)`; for higher powers
+///   it lies in `[1, 1000^|power|)`.
+/// - Zero, non-finite values and dimensionless units are returned unchanged.
+pub fn normalize_prefix(value: f64, unit: &Unit) -> (f64, Unit) {
+    let dominant: Option<&DimensionalUnit> = unit
+        .values
+        .iter()
+        .filter(|dimension| dimension.power != 0)
+        .max_by_key(|dimension| dimension.power.unsigned_abs());
+
+    let dimension: &DimensionalUnit = match dominant {
+        Some(dimension) if value != 0.0 && value.is_finite() => dimension,
+        _ => return (value, *unit),
+    };
+
+    let power: i32 = dimension.power as i32;
+    let unprefixed: f64 = value * 10f64.powi(dimension.prefix as i32 * power);
+
+    let thousands: i32 = (unprefixed.abs().log10() / (3 * power.abs()) as f64).floor() as i32;
+    let exponent: i32 = (3 * thousands * power.signum()).clamp(-24, 24);
+
+    let mut result: Unit = *unit;
+    result.set_prefix(
+        dimension.base,
+        UnitPrefix::from_exponent(exponent).unwrap_or(UnitPrefix::None),
+    );
+
+    (unprefixed / 10f64.powi(exponent * power), result)
+}
+
+/// Coherent SI derived units, expressed in powers of the base dimensions.
+impl Unit {
+    /// kg·m·s⁻²
+    pub fn newton() -> Self {
+        Unit::from_powers([1, 1, -2, 0, 0, 0, 0])
+    }
+
+    /// kg·m²·s⁻²
+    pub fn joule() -> Self {
+        Unit::from_powers([2, 1, -2, 0, 0, 0, 0])
+    }
+
+    /// kg·m²·s⁻³
+    pub fn watt() -> Self {
+        Unit::from_powers([2, 1, -3, 0, 0, 0, 0])
+    }
+
+    /// kg·m⁻¹·s⁻²
+    pub fn pascal() -> Self {
+        Unit::from_powers([-1, 1, -2, 0, 0, 0, 0])
+    }
+
+    /// s⁻¹
+    pub fn hertz() -> Self {
+        Unit::from_powers([0, 0, -1, 0, 0, 0, 0])
+    }
+
+    /// s·A
+    pub fn coulomb() -> Self {
+        Unit::from_powers([0, 0, 1, 1, 0, 0, 0])
+    }
+
+    /// kg·m²·s⁻³·A⁻¹
+    pub fn volt() -> Self {
+        Unit::from_powers([2, 1, -3, -1, 0, 0, 0])
+    }
+
+    /// kg·m²·s⁻³·A⁻²
+    pub fn ohm() -> Self {
+        Unit::from_powers([2, 1, -3, -2, 0, 0, 0])
+    }
+
+    /// kg⁻¹·m⁻²·s⁴·A²
+    pub fn farad() -> Self {
+        Unit::from_powers([-2, -1, 4, 2, 0, 0, 0])
+    }
+
+    /// kg·m²·s⁻²·A⁻¹
+    pub fn weber() -> Self {
+        Unit::from_powers([2, 1, -2, -1, 0, 0, 0])
+    }
+
+    /// kg·s⁻²·A⁻¹
+    pub fn tesla() -> Self {
+        Unit::from_powers([0, 1, -2, -1, 0, 0, 0])
+    }
+
+    /// kg·m²·s⁻²·A⁻²
+    pub fn henry() -> Self {
+        Unit::from_powers([2, 1, -2, -2, 0, 0, 0])
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for dimension in self.values.iter().filter(|dimension| dimension.power != 0) {
+            let mut symbol = dimension.base.get_symbol().to_string();
+            if dimension.prefix != UnitPrefix::None {
+                symbol.push_str(dimension.prefix.get_symbol());
             }
-            if self.values[i].power != 0 {
-                symbol.push(sup[self.values[i].power as usize]);
+            if dimension.power != 1 {
+                symbol.push_str(&superscript(dimension.power));
             }
-            print!("{}", symbol);
+            write!(f, "{}", symbol)?;
         }
+
+        Ok(())
+    }
+}
+
+impl Debug for Unit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Unit({})", self)
     }
 }
+
+/// Two units are equal when they have the same power and prefix on every base dimension.
+/// Prefixes of dimensions with a zero power are ignored.
+/// Use [`Unit::same_dimension`] to ignore prefixes entirely.
+impl PartialEq for Unit {
+    fn eq(&self, other: &Self) -> bool {
+        self.values
+            .iter()
+            .zip(other.values.iter())
+            .all(|(lhs, rhs)| {
+                lhs.power == rhs.power && (lhs.power == 0 || lhs.prefix == rhs.prefix)
+            })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseUnitError {
+    UnknownSymbol(char),
+    InvalidPower(String),
+}
+
+impl Display for ParseUnitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ParseUnitError::UnknownSymbol(symbol) => write!(
+                f,
+                "unknown dimension symbol '{}', expected one of L, M, T, I, Θ, N, J",
+                symbol
+            ),
+            ParseUnitError::InvalidPower(power) => {
+                write!(f, "invalid dimension power '{}'", power)
+            }
+        }
+    }
+}
+
+impl Error for ParseUnitError {}
+
+impl FromStr for Unit {
+    type Err = ParseUnitError;
+
+    /// Parses a dimension string such as `"M L^2 T^-2"` or `"LT⁻²"`.
+    /// - Each dimension symbol is optionally followed by a power, written either as `^-2` or as superscripts.
+    /// - Whitespace between terms is ignored and repeated symbols accumulate.
+    /// - Prefixes are not parsed; the resulting unit is unprefixed.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut powers: [i8; 7] = [0; 7];
+        let mut chars = s.chars().peekable();
+
+        while let Some(symbol) = chars.next() {
+            if symbol.is_whitespace() {
+                continue;
+            }
+
+            let index: usize = match symbol {
+                'L' => 0,
+                'M' => 1,
+                'T' => 2,
+                'I' => 3,
+                'Θ' => 4,
+                'N' => 5,
+                'J' => 6,
+                _ => return Err(ParseUnitError::UnknownSymbol(symbol)),
+            };
+
+            let mut digits: String = String::new();
+            let has_caret: bool = chars.next_if_eq(&'^').is_some();
+            if has_caret {
+                if let Some(sign) = chars.next_if_eq(&'-') {
+                    digits.push(sign);
+                }
+                while let Some(digit) = chars.next_if(|c| c.is_ascii_digit()) {
+                    digits.push(digit);
+                }
+            } else {
+                if chars.next_if_eq(&'⁻').is_some() {
+                    digits.push('-');
+                }
+                while let Some(digit) = chars.peek().and_then(|c| {
+                    SUPERSCRIPTS
+                        .iter()
+                        .position(|sup| sup == c)
+                        .and_then(|d| char::from_digit(d as u32, 10))
+                }) {
+                    chars.next();
+                    digits.push(digit);
+                }
+            }
+
+            let power: i8 = if digits.is_empty() && !has_caret {
+                1
+            } else {
+                digits
+                    .parse()
+                    .map_err(|_| ParseUnitError::InvalidPower(digits.clone()))?
+            };
+
+            powers[index] = powers[index]
+                .checked_add(power)
+                .ok_or(ParseUnitError::InvalidPower(digits))?;
+        }
+
+        Ok(Unit::from_powers(powers))
+    }
+}
+
+/// Multiplies the units, discarding any scale the prefixes cannot absorb.
+/// Use [`Unit::mul_scaled`] to keep it.
+impl Mul for Unit {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.combine(rhs, 1).0
+    }
+}
+
+/// Divides the units, discarding any scale the prefixes cannot absorb.
+/// Use [`Unit::div_scaled`] to keep it.
+impl Div for Unit {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.combine(rhs, -1).0
+    }
+}
+
+#[inline]
+fn superscript(power: i8) -> String {
+    let mut result: String = String::new();
+    if power < 0 {
+        result.push('⁻');
+    }
+
+    for digit in power.unsigned_abs().to_string().chars() {
+        result.push(SUPERSCRIPTS[digit as usize - '0' as usize]);
+    }
+
+    result
+}
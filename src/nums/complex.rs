@@ -1,12 +1,163 @@
+use super::super::angles::Angle;
+
 use num::Complex as NumComplex;
-use num::Num;
+use num::{Num, One, Signed, Zero};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
+#[derive(Clone, Copy)]
 pub struct Complex<T>(NumComplex<T>);
 
 impl<T> Complex<T> {
     pub fn new(re: T, im: T) -> Self {
         Complex(NumComplex::new(re, im))
     }
+
+    /// Unwraps the underlying `num::Complex` value.
+    pub fn into_inner(self) -> NumComplex<T> {
+        self.0
+    }
+
+    pub fn re(&self) -> T
+    where
+        T: Clone,
+    {
+        self.0.re.clone()
+    }
+
+    pub fn im(&self) -> T
+    where
+        T: Clone,
+    {
+        self.0.im.clone()
+    }
+
+    /// Returns the complex conjugate `a - bi`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::nums::complex::Complex;
+    ///
+    /// let z: Complex<i32> = Complex::new(3, 4).conjugate();
+    ///
+    /// assert_eq!(z.re(), 3);
+    /// assert_eq!(z.im(), -4);
+    /// ```
+    pub fn conjugate(&self) -> Complex<T>
+    where
+        T: Clone + std::ops::Neg<Output = T>,
+    {
+        Complex::new(self.0.re.clone(), -self.0.im.clone())
+    }
+
+    /// Returns the modulus `|z|`, computed with `hypot` to avoid overflow.
+    pub fn modulus(&self) -> f64
+    where
+        T: Clone + Into<f64>,
+    {
+        self.re().into().hypot(self.im().into())
+    }
+
+    /// Returns the argument (phase) of the complex number in `(-π, π]`.
+    pub fn argument(&self) -> Angle
+    where
+        T: Clone + Into<f64>,
+    {
+        Angle::from_atan2(self.im().into(), self.re().into())
+    }
+
+    /// Returns the polar form `(r, θ)` of the complex number.
+    pub fn to_polar(&self) -> (f64, Angle)
+    where
+        T: Clone + Into<f64>,
+    {
+        (self.modulus(), self.argument())
+    }
+}
+
+impl Complex<f64> {
+    /// Creates a complex number from its polar form `r(cos θ + i sin θ)`.
+    pub fn from_polar(r: f64, theta: Angle) -> Complex<f64> {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+
+    /// Returns `e^z`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::f64::consts::PI;
+    /// use vectra::nums::complex::Complex;
+    ///
+    /// let z: Complex<f64> = Complex::new(0.0, PI).exp();
+    ///
+    /// assert!((z.re() + 1.0).abs() < 1e-12);
+    /// assert!(z.im().abs() < 1e-12);
+    /// ```
+    pub fn exp(&self) -> Complex<f64> {
+        Complex(self.0.exp())
+    }
+
+    /// Returns the principal natural logarithm, with the imaginary part in `(-π, π]`.
+    pub fn ln(&self) -> Complex<f64> {
+        Complex(self.0.ln())
+    }
+
+    /// Returns the principal square root, with a non-negative real part.
+    pub fn sqrt(&self) -> Complex<f64> {
+        Complex(self.0.sqrt())
+    }
+
+    /// Raises the complex number to an integer power using exponentiation by squaring.
+    /// Negative powers return the reciprocal of the positive power.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::nums::complex::Complex;
+    ///
+    /// let i: Complex<f64> = Complex::new(0.0, 1.0);
+    ///
+    /// assert_eq!(i.powi(2), Complex::new(-1.0, 0.0));
+    /// ```
+    pub fn powi(&self, n: i32) -> Complex<f64> {
+        let mut base: NumComplex<f64> = self.0;
+        let mut exp: u32 = n.unsigned_abs();
+        let mut result: NumComplex<f64> = NumComplex::new(1.0, 0.0);
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+
+        if n < 0 {
+            Complex(result.inv())
+        } else {
+            Complex(result)
+        }
+    }
+
+    /// Raises the complex number to a real power via the polar form `r^x e^(ixθ)`.
+    pub fn powf(&self, x: f64) -> Complex<f64> {
+        let (r, theta) = self.to_polar();
+
+        Complex::from_polar(r.powf(x), theta * x)
+    }
+}
+
+impl<T> From<(T, T)> for Complex<T> {
+    fn from((re, im): (T, T)) -> Self {
+        Complex::new(re, im)
+    }
+}
+
+impl<T> From<NumComplex<T>> for Complex<T> {
+    fn from(value: NumComplex<T>) -> Self {
+        Complex(value)
+    }
 }
 
 impl<T> std::ops::Add for Complex<T>
@@ -22,3 +173,267 @@ where
         Complex(NumComplex::new(re, im))
     }
 }
+
+impl<T> std::ops::Sub for Complex<T>
+where
+    T: Num + std::ops::Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let re: T = self.0.re - rhs.0.re;
+        let im: T = self.0.im - rhs.0.im;
+
+        Complex(NumComplex::new(re, im))
+    }
+}
+
+impl<T> std::ops::Mul for Complex<T>
+where
+    T: Num + Clone,
+{
+    type Output = Self;
+
+    /// Multiplies two complex numbers using `(a+bi)(c+di) = (ac-bd) + (ad+bc)i`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::nums::complex::Complex;
+    ///
+    /// let z: Complex<i32> = Complex::new(1, 2) * Complex::new(3, 4);
+    ///
+    /// assert_eq!(z.re(), -5);
+    /// assert_eq!(z.im(), 10);
+    /// ```
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self.0.re, self.0.im);
+        let (c, d) = (rhs.0.re, rhs.0.im);
+
+        let re: T = a.clone() * c.clone() - b.clone() * d.clone();
+        let im: T = a * d + b * c;
+
+        Complex(NumComplex::new(re, im))
+    }
+}
+
+impl<T> std::ops::Mul<T> for Complex<T>
+where
+    T: Num + Clone,
+{
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        Complex(NumComplex::new(
+            self.0.re * scalar.clone(),
+            self.0.im * scalar,
+        ))
+    }
+}
+
+impl<T> std::ops::Div for Complex<T>
+where
+    T: Num + Clone,
+{
+    type Output = Self;
+
+    /// Divides two complex numbers using
+    /// `(a+bi)/(c+di) = ((ac+bd) + (bc-ad)i) / (c²+d²)`.
+    /// - For floats, division by zero yields non-finite components.
+    /// - For integers, each component is truncated and division by zero panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::nums::complex::Complex;
+    ///
+    /// let z: Complex<f64> = Complex::new(-5.0, 10.0) / Complex::new(3.0, 4.0);
+    ///
+    /// assert!((z.re() - 1.0).abs() < 1e-12);
+    /// assert!((z.im() - 2.0).abs() < 1e-12);
+    /// ```
+    fn div(self, rhs: Self) -> Self {
+        let (a, b) = (self.0.re, self.0.im);
+        let (c, d) = (rhs.0.re, rhs.0.im);
+
+        let denominator: T = c.clone() * c.clone() + d.clone() * d.clone();
+        let re: T = (a.clone() * c.clone() + b.clone() * d.clone()) / denominator.clone();
+        let im: T = (b * c - a * d) / denominator;
+
+        Complex(NumComplex::new(re, im))
+    }
+}
+
+impl<T> std::ops::Rem for Complex<T>
+where
+    T: Num + Clone,
+{
+    type Output = Self;
+
+    /// Returns the remainder `self - rhs · q`, where `q` is the component-wise truncated quotient,
+    /// matching `num::Complex`.
+    fn rem(self, rhs: Self) -> Self {
+        Complex(self.0 % rhs.0)
+    }
+}
+
+impl<T> std::ops::AddAssign for Complex<T>
+where
+    T: Num + Clone,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.0.re = self.0.re.clone() + rhs.0.re;
+        self.0.im = self.0.im.clone() + rhs.0.im;
+    }
+}
+
+impl<T> std::ops::SubAssign for Complex<T>
+where
+    T: Num + Clone,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0.re = self.0.re.clone() - rhs.0.re;
+        self.0.im = self.0.im.clone() - rhs.0.im;
+    }
+}
+
+impl<T> std::ops::MulAssign for Complex<T>
+where
+    T: Num + Clone,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        let (a, b) = (self.0.re.clone(), self.0.im.clone());
+        let (c, d) = (rhs.0.re, rhs.0.im);
+
+        self.0.re = a.clone() * c.clone() - b.clone() * d.clone();
+        self.0.im = a * d + b * c;
+    }
+}
+
+impl<T> std::ops::Neg for Complex<T>
+where
+    T: std::ops::Neg<Output = T>,
+{
+    type Output = Self;
+
+    /// Negates both parts of the complex number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::nums::complex::Complex;
+    ///
+    /// assert_eq!(-Complex::new(1, -2), Complex::new(-1, 2));
+    /// ```
+    fn neg(self) -> Self {
+        Complex(NumComplex::new(-self.0.re, -self.0.im))
+    }
+}
+
+impl<T> Zero for Complex<T>
+where
+    T: Num,
+{
+    /// Returns the additive identity `0 + 0i`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use num::Zero;
+    /// use vectra::nums::complex::Complex;
+    ///
+    /// assert_eq!(Complex::zero() + Complex::new(1, 2), Complex::new(1, 2));
+    /// ```
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.re.is_zero() && self.0.im.is_zero()
+    }
+}
+
+impl<T> One for Complex<T>
+where
+    T: Num + Clone,
+{
+    /// Returns the multiplicative identity `1 + 0i`.
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+}
+
+impl<T> Num for Complex<T>
+where
+    T: Num + Clone,
+{
+    type FromStrRadixErr = num::complex::ParseComplexError<T::FromStrRadixErr>;
+
+    /// Parses `a+bi` style strings in the given radix, as `num::Complex` does.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        NumComplex::from_str_radix(s, radix).map(Complex)
+    }
+}
+
+impl<T> PartialEq for Complex<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Debug for Complex<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Complex")
+            .field("re", &self.0.re)
+            .field("im", &self.0.im)
+            .finish()
+    }
+}
+
+impl<T> Display for Complex<T>
+where
+    T: Num + Clone + Display + PartialOrd + Signed,
+{
+    /// Formats the complex number as `a+bi`, omitting zero parts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::nums::complex::Complex;
+    ///
+    /// assert_eq!(format!("{}", Complex::new(3, 4)), "3+4i");
+    /// assert_eq!(format!("{}", Complex::new(3, -4)), "3-4i");
+    /// assert_eq!(format!("{}", Complex::new(0, 4)), "4i");
+    /// assert_eq!(format!("{}", Complex::new(0, -4)), "-4i");
+    /// assert_eq!(format!("{}", Complex::new(3, 0)), "3");
+    /// assert_eq!(format!("{}", Complex::new(0, 0)), "0");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let z: T = T::zero();
+        let mut result: String = String::new();
+
+        if self.0.re != z || self.0.im == z {
+            result.push_str(&format!("{}", self.0.re));
+        }
+
+        if self.0.im != z {
+            if self.0.im > z {
+                if self.0.re != z {
+                    result.push('+');
+                }
+            } else {
+                result.push('-');
+            }
+
+            result.push_str(&format!("{}i", self.0.im.abs()));
+        }
+
+        write!(f, "{}", result)
+    }
+}
@@ -1 +1,2 @@
 pub mod complex;
+pub mod quaternion;
@@ -0,0 +1,188 @@
+use super::super::angles::Angle;
+use super::super::vectors::vector3d::Vector3D;
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A quaternion `w + xi + yj + zk`.
+///
+/// Unit quaternions represent 3D rotations; multiplying two of them composes the rotations,
+/// with `a * b` applying `b` first and then `a`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion<T> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Quaternion<T> {
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// Returns the conjugate `w - xi - yj - zk`, which is the inverse rotation for a unit quaternion.
+    pub fn conjugate(&self) -> Quaternion<T>
+    where
+        T: Clone + Neg<Output = T>,
+    {
+        Quaternion::new(
+            self.w.clone(),
+            -self.x.clone(),
+            -self.y.clone(),
+            -self.z.clone(),
+        )
+    }
+}
+
+impl Quaternion<f64> {
+    /// Creates the unit quaternion rotating by `angle` around `axis` (right-hand rule).
+    /// The axis is normalized first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::angles::Angle;
+    /// use vectra::nums::quaternion::Quaternion;
+    /// use vectra::vectors::vector3d::Vector3D;
+    ///
+    /// let q = Quaternion::from_axis_angle(&Vector3D::new(0.0, 0.0, 1.0), Angle::RIGHT);
+    /// let v = q.rotate(&Vector3D::new(1.0, 0.0, 0.0));
+    ///
+    /// assert!(v.x.abs() < 1e-12);
+    /// assert!((v.y - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn from_axis_angle(axis: &Vector3D<f64>, angle: Angle) -> Quaternion<f64> {
+        let k: Vector3D<f64> = axis.normalize();
+        let half: Angle = angle / 2.0;
+        let sin: f64 = half.sin();
+
+        Quaternion::new(half.cos(), k.x * sin, k.y * sin, k.z * sin)
+    }
+
+    pub fn norm(&self) -> f64 {
+        (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    pub fn normalize(&self) -> Quaternion<f64> {
+        let norm: f64 = self.norm();
+
+        Quaternion::new(self.w / norm, self.x / norm, self.y / norm, self.z / norm)
+    }
+
+    /// Rotates `v` by this quaternion, computing `q v q*` without building the full products.
+    /// The quaternion is expected to be of unit length.
+    pub fn rotate(&self, v: &Vector3D<f64>) -> Vector3D<f64> {
+        let u: Vector3D<f64> = Vector3D::new(self.x, self.y, self.z);
+        let t: Vector3D<f64> = u.cross(v) * 2.0;
+
+        *v + t * self.w + u.cross(&t)
+    }
+
+    /// Returns the row-major rotation matrix of this unit quaternion,
+    /// matching [`Vector3D::rotation_matrix`] for the same axis and angle.
+    pub fn to_rotation_matrix(&self) -> [[f64; 3]; 3] {
+        let Quaternion { w, x, y, z } = *self;
+
+        [
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ]
+    }
+
+    /// Recovers the unit quaternion (with `w ≥ 0`) from a row-major rotation matrix.
+    ///
+    /// Uses Shepperd's method, branching on the largest diagonal term so the square root
+    /// never operates near zero.
+    pub fn from_rotation_matrix(m: &[[f64; 3]; 3]) -> Quaternion<f64> {
+        let trace: f64 = m[0][0] + m[1][1] + m[2][2];
+
+        let q: Quaternion<f64> = if trace > 0.0 {
+            let s: f64 = (trace + 1.0).sqrt() * 2.0;
+            Quaternion::new(
+                s / 4.0,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s: f64 = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+            Quaternion::new(
+                (m[2][1] - m[1][2]) / s,
+                s / 4.0,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s: f64 = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+            Quaternion::new(
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                s / 4.0,
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s: f64 = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+            Quaternion::new(
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s / 4.0,
+            )
+        };
+
+        if q.w < 0.0 {
+            Quaternion::new(-q.w, -q.x, -q.y, -q.z)
+        } else {
+            q
+        }
+    }
+}
+
+/// The Hamilton product.
+impl<T> Mul for Quaternion<T>
+where
+    T: Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let Quaternion {
+            w: a1,
+            x: b1,
+            y: c1,
+            z: d1,
+        } = self;
+        let Quaternion {
+            w: a2,
+            x: b2,
+            y: c2,
+            z: d2,
+        } = rhs;
+
+        Quaternion::new(
+            a1.clone() * a2.clone()
+                - b1.clone() * b2.clone()
+                - c1.clone() * c2.clone()
+                - d1.clone() * d2.clone(),
+            a1.clone() * b2.clone() + b1.clone() * a2.clone() + c1.clone() * d2.clone()
+                - d1.clone() * c2.clone(),
+            a1.clone() * c2.clone() - b1.clone() * d2.clone()
+                + c1.clone() * a2.clone()
+                + d1.clone() * b2.clone(),
+            a1 * d2 + b1 * c2 - c1 * b2 + d1 * a2,
+        )
+    }
+}
@@ -1,177 +1,1215 @@
-use super::traits::Numeric;
+pub mod signal;
+pub mod sparse;
 
-use num::{Complex as NumComplex, Num, Signed, Zero};
+use super::nums::complex::Complex;
+use super::traits::{Field, Numeric};
+
+use num::{Complex as NumComplex, Integer, Num, Signed, Zero};
 use std::{
     cmp::PartialOrd,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
-    ops::{Add, Mul, Neg, Sub},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
 };
 
-#[derive(Clone)]
-pub struct Polynomial<T> {
-    degree: usize,
-    coefficients: Vec<T>,
+#[derive(Clone)]
+pub struct Polynomial<T> {
+    degree: usize,
+    coefficients: Vec<T>,
+}
+
+impl<T> Default for Polynomial<T>
+where
+    T: Num + Clone,
+{
+    /// Creates a new polynomial with all coefficients set to zero.
+    /// - The degree of the polynomial is set to zero.
+    /// - The coefficients are stored in a vector of length one.
+    /// - The first element of the vector is the coefficient of the constant term.
+    ///
+    /// Note: The degree of the zero polynomial is set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::default();
+    ///
+    /// assert_eq!(p.degree(), 0);
+    /// assert_eq!(p.coefficients(), &vec![0]);
+    /// ```
+    fn default() -> Self {
+        Self {
+            degree: 0,
+            coefficients: vec![T::zero(); 1],
+        }
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Num + Clone,
+{
+    /// Creates a new polynomial with all coefficients set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::new();
+    ///
+    /// assert_eq!(p.degree(), 0);
+    /// assert_eq!(p.coefficients(), &vec![0]);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T> Polynomial<T> {
+    /// Creates a new polynomial from a vector of coefficients.
+    /// - The degree of the polynomial is set to the length of the vector minus one.
+    /// - The coefficients are stored in the vector in increasing order of degree.
+    /// - The first element of the vector is the coefficient of the lowest degree term (i.e., a constant).
+    /// - The last element of the vector is the coefficient of the highest degree term.
+    ///
+    /// Any missing coefficients are set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    /// let p1: Polynomial<i32> = Polynomial::from_coefficients(vec![]);
+    /// let p2: Polynomial<i32> = Polynomial::from_coefficients(vec![1]);
+    /// let p3: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4]);
+    /// let p4: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    ///
+    /// assert_eq!(p1.degree(), 0);
+    /// assert_eq!(p1.coefficients(), &vec![0]);
+    ///
+    /// assert_eq!(p2.degree(), 0);
+    /// assert_eq!(p2.coefficients(), &vec![1]);
+    ///
+    /// assert_eq!(p3.degree(), 1);
+    /// assert_eq!(p3.coefficients(), &vec![1, 4]);
+    ///
+    /// assert_eq!(p4.degree(), 2);
+    /// assert_eq!(p4.coefficients(), &vec![1, 4, 5]);
+    /// ```
+    pub fn from_coefficients(coefficients: Vec<T>) -> Self
+    where
+        T: Num + Clone,
+    {
+        if coefficients.is_empty() {
+            return Self::new();
+        }
+
+        let degree: usize = coefficients.len() - 1;
+        Self {
+            degree,
+            coefficients,
+        }
+    }
+
+    /// Creates a polynomial of exactly the given degree from its coefficients,
+    /// padding with zeros or dropping the higher coefficients to get `degree + 1` entries.
+    /// The leading coefficient may be zero, so the stored degree can exceed the true degree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::with_degree(vec![1, 2], 4);
+    ///
+    /// assert_eq!(p.degree(), 4);
+    /// assert_eq!(p.coefficients(), &vec![1, 2, 0, 0, 0]);
+    /// assert_eq!(Polynomial::with_degree(vec![1, 2, 3], 1).coefficients(), &vec![1, 2]);
+    /// ```
+    pub fn with_degree(mut coefficients: Vec<T>, degree: usize) -> Self
+    where
+        T: Num + Clone,
+    {
+        coefficients.resize(degree + 1, T::zero());
+
+        Self {
+            degree,
+            coefficients,
+        }
+    }
+
+    /// Creates a polynomial from sparse `(degree, coefficient)` pairs.
+    /// Coefficients of repeated degrees are summed, and missing degrees are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// // x^100 + 1
+    /// let p: Polynomial<i32> = Polynomial::from_terms(&[(100, 1), (0, 1)]);
+    ///
+    /// assert_eq!(p.degree(), 100);
+    /// assert_eq!(p.evaluate(1), 2);
+    /// assert_eq!(p.coefficients().iter().filter(|&&c| c != 0).count(), 2);
+    /// ```
+    pub fn from_terms(terms: &[(usize, T)]) -> Self
+    where
+        T: Num + Clone,
+    {
+        let degree: usize = terms.iter().map(|(degree, _)| *degree).max().unwrap_or(0);
+        let mut coefficients: Vec<T> = vec![T::zero(); degree + 1];
+
+        for (degree, coefficient) in terms {
+            coefficients[*degree] = coefficients[*degree].clone() + coefficient.clone();
+        }
+
+        Self {
+            degree,
+            coefficients,
+        }
+    }
+
+    /// Returns the degree of the polynomial.
+    /// - The degree of a polynomial is the highest power of the variable in the polynomial.
+    /// - The degree of a constant polynomial is zero.
+    ///
+    /// Note: The degree of the zero polynomial is set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    ///
+    /// assert_eq!(p.degree(), 2);
+    /// ```
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn coefficients(&self) -> &Vec<T> {
+        &self.coefficients
+    }
+
+    /// Returns a new polynomial with `f` applied to every coefficient.
+    /// The degree is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    ///
+    /// let q: Polynomial<f64> = p.map(|c| *c as f64 / 2.0);
+    ///
+    /// assert_eq!(q.coefficients(), &vec![0.5, 2.0, 2.5]);
+    /// ```
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Polynomial<U> {
+        Polynomial {
+            degree: self.degree,
+            coefficients: self.coefficients.iter().map(f).collect(),
+        }
+    }
+
+    /// Applies `f` to every coefficient in place.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let mut p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    ///
+    /// p.map_in_place(|c| *c *= 3);
+    ///
+    /// assert_eq!(p.coefficients(), &vec![3, 12, 15]);
+    /// ```
+    pub fn map_in_place<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.coefficients.iter_mut().for_each(f);
+    }
+
+    /// Runs Horner's scheme with a custom step, visiting coefficients from the highest degree down
+    /// as `acc = f(acc, c)`. With `f = |acc, c| acc * x + c` and `init = 0` this is [`Polynomial::evaluate`],
+    /// but any semiring or accumulator can be plugged in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    ///
+    /// assert_eq!(p.fold_horner(0, |acc, c| acc * 2 + c), p.evaluate(2));
+    ///
+    /// // Max-plus (tropical) evaluation: max over terms of c + degree * x
+    /// let tropical = p.fold_horner(f64::NEG_INFINITY, |acc, &c| (acc + 3.0).max(c as f64));
+    /// assert_eq!(tropical, 11.0);
+    /// ```
+    pub fn fold_horner<A, F: Fn(A, &T) -> A>(&self, init: A, f: F) -> A {
+        self.coefficients.iter().rev().fold(init, f)
+    }
+
+    /// Returns the polynomial with every term above `max_degree` dropped,
+    /// i.e. the power series truncated modulo `x^(max_degree + 1)`.
+    /// If `max_degree` is at least the current degree, this is a clone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// // x^3 + x^2 + x + 1
+    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 1, 1, 1]);
+    ///
+    /// let q: Polynomial<i32> = p.truncate(1);
+    ///
+    /// assert_eq!(q.degree(), 1);
+    /// assert_eq!(q.coefficients(), &vec![1, 1]);
+    /// assert_eq!(p.truncate(10).coefficients(), p.coefficients());
+    /// ```
+    pub fn truncate(&self, max_degree: usize) -> Polynomial<T>
+    where
+        T: Clone,
+    {
+        let degree: usize = self.degree.min(max_degree);
+
+        Polynomial {
+            degree,
+            coefficients: self.coefficients[..=degree].to_vec(),
+        }
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Num + Clone,
+{
+    /// Returns the coefficient of the term with the given degree.
+    /// If the degree is greater than the degree of the polynomial, None is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    ///
+    /// assert_eq!(p.get_coefficient(0), Some(1));
+    /// assert_eq!(p.get_coefficient(1), Some(4));
+    /// assert_eq!(p.get_coefficient(2), Some(5));
+    /// assert_eq!(p.get_coefficient(3), None);
+    /// ```
+    pub fn get_coefficient(&self, degree: usize) -> Option<T> {
+        if degree > self.degree {
+            None
+        } else {
+            Some(self.coefficients[degree].clone())
+        }
+    }
+
+    pub fn set_degree(&mut self, degree: usize) {
+        if degree > self.degree {
+            self.degree = degree;
+            self.coefficients.resize(degree + 1, T::zero());
+        }
+    }
+
+    pub fn set_coefficient(&mut self, degree: usize, coefficient: T) {
+        self.set_degree(degree);
+        self.coefficients[degree] = coefficient;
+    }
+
+    /// Returns the degree of the highest non-zero term, or None for the zero polynomial.
+    fn leading_degree(&self) -> Option<usize> {
+        self.coefficients.iter().rposition(|c| !c.is_zero())
+    }
+
+    /// Removes trailing zero coefficients so that the degree matches the highest non-zero term.
+    /// The zero polynomial is trimmed to a single zero coefficient of degree zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let mut p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 0, 0]);
+    ///
+    /// p.trim();
+    ///
+    /// assert_eq!(p.degree(), 1);
+    /// assert_eq!(p.coefficients(), &vec![1, 4]);
+    /// ```
+    pub fn trim(&mut self) {
+        self.degree = self.leading_degree().unwrap_or(0);
+        self.coefficients.truncate(self.degree + 1);
+    }
+
+    /// Returns true if every coefficient is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// assert!(Polynomial::<i32>::new().is_zero());
+    /// assert!(Polynomial::from_coefficients(vec![0, 0, 0]).is_zero());
+    /// assert!(!Polynomial::from_coefficients(vec![0, 0, 1]).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.leading_degree().is_none()
+    }
+
+    /// Returns true if the polynomial has degree zero once trailing zero coefficients are ignored.
+    /// The zero polynomial is considered constant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// assert!(Polynomial::from_coefficients(vec![7, 0, 0]).is_constant());
+    /// assert!(Polynomial::<i32>::new().is_constant());
+    /// assert!(!Polynomial::from_coefficients(vec![7, 1]).is_constant());
+    /// ```
+    pub fn is_constant(&self) -> bool {
+        self.leading_degree().unwrap_or(0) == 0
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    ///
+    /// assert_eq!(p.evaluate(2), 29);
+    /// ```
+    pub fn evaluate(&self, x: T) -> T {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    /// Returns the derivative of the polynomial.
+    /// The derivative of a constant polynomial is the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    ///
+    /// assert_eq!(p.derivative().coefficients(), &vec![4, 10]);
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let mut factor: T = T::zero();
+        let coefficients: Vec<T> = self
+            .coefficients
+            .iter()
+            .skip(1)
+            .map(|c| {
+                factor = factor.clone() + T::one();
+                c.clone() * factor.clone()
+            })
+            .collect();
+
+        Self::from_coefficients(coefficients)
+    }
+
+    /// Adds `other` into `self` in place, growing the coefficient vector only when
+    /// `other` has a higher degree. Equivalent to `self = self + other` without a fresh allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let mut p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    ///
+    /// p.add_assign_ref(&Polynomial::from_coefficients(vec![2, 8, 10, 12]));
+    ///
+    /// assert_eq!(p.coefficients(), &vec![3, 12, 15, 12]);
+    /// ```
+    pub fn add_assign_ref(&mut self, other: &Polynomial<T>) {
+        self.set_degree(other.degree);
+
+        for (lhs, rhs) in self.coefficients.iter_mut().zip(other.coefficients.iter()) {
+            *lhs = lhs.clone() + rhs.clone();
+        }
+    }
+
+    /// Multiplies `self` by `other` in place, reusing the coefficient vector.
+    /// Coefficients are computed from the highest degree down, so each one only overwrites a slot
+    /// that no lower-degree coefficient still needs.
+    pub fn mul_assign_ref(&mut self, other: &Polynomial<T>) {
+        let (n, m): (usize, usize) = (self.degree, other.degree);
+        self.set_degree(n + m);
+
+        for k in (0..=n + m).rev() {
+            let mut sum: T = T::zero();
+
+            for i in k.saturating_sub(m)..=k.min(n) {
+                sum = sum + self.coefficients[i].clone() * other.coefficients[k - i].clone();
+            }
+
+            self.coefficients[k] = sum;
+        }
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Field,
+{
+    /// Divides the polynomial by `divisor`, returning `(quotient, remainder)`
+    /// such that `self = quotient * divisor + remainder` and the remainder has a lower degree than the divisor.
+    /// The coefficients must form a field (see [`Field`]), so every coefficient division is exact.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// // (x^2 + 3x + 5) / (x + 1) = (x + 2) remainder 3
+    /// let p: Polynomial<f64> = Polynomial::from_coefficients(vec![5.0, 3.0, 1.0]);
+    /// let d: Polynomial<f64> = Polynomial::from_coefficients(vec![1.0, 1.0]);
+    ///
+    /// let (q, r) = p.div_rem(&d);
+    ///
+    /// assert_eq!(q.coefficients(), &vec![2.0, 1.0]);
+    /// assert_eq!(r.coefficients(), &vec![3.0]);
+    /// ```
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let d: usize = divisor
+            .leading_degree()
+            .expect("attempt to divide a polynomial by the zero polynomial");
+        let n: usize = match self.leading_degree() {
+            Some(n) if n >= d => n,
+            _ => return (Self::new(), self.clone()),
+        };
+
+        let lead: T = divisor.coefficients[d].clone();
+        let mut remainder: Vec<T> = self.coefficients[..=n].to_vec();
+        let mut quotient: Vec<T> = vec![T::zero(); n - d + 1];
+
+        for i in (0..=n - d).rev() {
+            let c: T = remainder[i + d].clone() / lead.clone();
+
+            for (j, coefficient) in divisor.coefficients[..=d].iter().enumerate() {
+                remainder[i + j] = remainder[i + j].clone() - c.clone() * coefficient.clone();
+            }
+
+            quotient[i] = c;
+        }
+
+        remainder.truncate(d);
+
+        (
+            Self::from_coefficients(quotient),
+            Self::from_coefficients(remainder),
+        )
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Num + Clone,
+{
+    /// Evaluates the polynomial at a square matrix `A` using Horner's method,
+    /// i.e. `p(A) = c₀I + c₁A + c₂A² + ...`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// // p(x) = x^2
+    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![0, 0, 1]);
+    /// let a: Vec<Vec<i32>> = vec![vec![1, 2], vec![3, 4]];
+    ///
+    /// assert_eq!(p.evaluate_matrix(&a), vec![vec![7, 10], vec![15, 22]]);
+    /// ```
+    pub fn evaluate_matrix(&self, a: &[Vec<T>]) -> Vec<Vec<T>> {
+        let n: usize = a.len();
+        assert!(
+            a.iter().all(|row| row.len() == n),
+            "matrix must be square to evaluate a polynomial at it"
+        );
+
+        let mut result: Vec<Vec<T>> = vec![vec![T::zero(); n]; n];
+
+        for coefficient in self.coefficients.iter().rev() {
+            let mut next: Vec<Vec<T>> = vec![vec![T::zero(); n]; n];
+
+            for i in 0..n {
+                for j in 0..n {
+                    for (k, row) in a.iter().enumerate() {
+                        next[i][j] = next[i][j].clone() + result[i][k].clone() * row[j].clone();
+                    }
+                }
+
+                next[i][i] = next[i][i].clone() + coefficient.clone();
+            }
+
+            result = next;
+        }
+
+        result
+    }
+}
+
+impl Polynomial<i64> {
+    /// Returns the content of the polynomial, the GCD of all its coefficients.
+    /// - The sign of the content matches the sign of the leading non-zero coefficient,
+    ///   so that the primitive part always has a positive leading coefficient.
+    /// - The content of the zero polynomial is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i64> = Polynomial::from_coefficients(vec![6, 4, 2]);
+    /// let q: Polynomial<i64> = Polynomial::from_coefficients(vec![6, 4, -2]);
+    ///
+    /// assert_eq!(p.content(), 2);
+    /// assert_eq!(q.content(), -2);
+    /// assert_eq!(Polynomial::<i64>::new().content(), 0);
+    /// ```
+    pub fn content(&self) -> i64 {
+        let gcd: i64 = self
+            .coefficients
+            .iter()
+            .fold(0, |acc, coefficient| acc.gcd(coefficient));
+
+        match self.coefficients.iter().rev().find(|c| **c != 0) {
+            Some(leading) if *leading < 0 => -gcd,
+            _ => gcd,
+        }
+    }
+
+    /// Returns the polynomial divided by its content.
+    /// The zero polynomial is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i64> = Polynomial::from_coefficients(vec![6, 4, 2]);
+    ///
+    /// assert_eq!(p.primitive_part().coefficients(), &vec![3, 2, 1]);
+    /// ```
+    pub fn primitive_part(&self) -> Polynomial<i64> {
+        let content: i64 = self.content();
+
+        if content == 0 {
+            return self.clone();
+        }
+
+        Polynomial::from_coefficients(self.coefficients.iter().map(|c| c / content).collect())
+    }
+}
+
+impl Polynomial<i32> {
+    /// Adds two polynomials, returning `None` if any coefficient overflows `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, i32::MAX]);
+    ///
+    /// assert!(p.checked_add(&Polynomial::from_coefficients(vec![1])).is_some());
+    /// assert!(p.checked_add(&Polynomial::from_coefficients(vec![0, 1])).is_none());
+    /// ```
+    pub fn checked_add(&self, other: &Polynomial<i32>) -> Option<Polynomial<i32>> {
+        let degree: usize = self.degree.max(other.degree);
+
+        let coefficients: Option<Vec<i32>> = (0..=degree)
+            .map(|i| {
+                let lhs: i32 = self.coefficients.get(i).copied().unwrap_or(0);
+                let rhs: i32 = other.coefficients.get(i).copied().unwrap_or(0);
+
+                lhs.checked_add(rhs)
+            })
+            .collect();
+
+        Some(Polynomial::from_coefficients(coefficients?))
+    }
+
+    /// Multiplies two polynomials, returning `None` if any product or partial sum overflows `i32`.
+    /// Intermediate sums are checked too, so a result can be rejected even if the
+    /// final coefficients would fit.
+    pub fn checked_mul(&self, other: &Polynomial<i32>) -> Option<Polynomial<i32>> {
+        let mut coefficients: Vec<i32> = vec![0; self.degree + other.degree + 1];
+
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] = coefficients[i + j].checked_add(a.checked_mul(b)?)?;
+            }
+        }
+
+        Some(Polynomial::from_coefficients(coefficients))
+    }
+}
+
+impl Polynomial<u64> {
+    /// Evaluates the polynomial at `x` in the ring of integers modulo `modulus`.
+    /// - Coefficients and `x` are reduced mod `modulus` too.
+    /// - Horner's method is reduced at every step through `u128`, so nothing overflows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// // 1 + 4x + 5x^2 at x = 2 is 29, which is 1 mod 7
+    /// let p: Polynomial<u64> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    ///
+    /// assert_eq!(p.evaluate_mod(2, 7), 1);
+    /// ```
+    pub fn evaluate_mod(&self, x: u64, modulus: u64) -> u64 {
+        assert!(modulus != 0, "modulus must be non-zero");
+
+        let (x, modulus): (u128, u128) = (x as u128 % modulus as u128, modulus as u128);
+
+        self.coefficients.iter().rev().fold(0, |acc, &c| {
+            ((acc as u128 * x + c as u128 % modulus) % modulus) as u64
+        })
+    }
+}
+
+impl Polynomial<f64> {
+    /// Returns the first `n` coefficients of the power series `1 / self`,
+    /// computed by Newton iteration `g ← g(2 - fg) mod x^k` with doubling precision.
+    /// - Returns `None` if the constant term is zero, since the series does not exist.
+    /// - For `n = 0` the zero polynomial is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// // 1 / (1 - x) = 1 + x + x^2 + ...
+    /// let p: Polynomial<f64> = Polynomial::from_coefficients(vec![1.0, -1.0]);
+    ///
+    /// let inverse: Polynomial<f64> = p.inverse_series(4).unwrap();
+    ///
+    /// assert_eq!(inverse.coefficients(), &vec![1.0, 1.0, 1.0, 1.0]);
+    /// assert!(Polynomial::from_coefficients(vec![0.0, 1.0]).inverse_series(4).is_none());
+    /// ```
+    pub fn inverse_series(&self, n: usize) -> Option<Polynomial<f64>> {
+        if self.coefficients[0] == 0.0 {
+            return None;
+        }
+
+        if n == 0 {
+            return Some(Polynomial::new());
+        }
+
+        let mut inverse: Vec<f64> = vec![1.0 / self.coefficients[0]];
+        let mut precision: usize = 1;
+
+        while precision < n {
+            precision = (precision * 2).min(n);
+
+            let mut error: Vec<f64> = truncated_product(&self.coefficients, &inverse, precision)
+                .iter()
+                .map(|c| -c)
+                .collect();
+            error[0] += 2.0;
+
+            inverse = truncated_product(&inverse, &error, precision);
+        }
+
+        Some(Polynomial::from_coefficients(inverse))
+    }
+
+    /// Rounds every coefficient to `decimals` decimal places, cleaning up round-off
+    /// such as `2.9999999` left behind by floating-point arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<f64> = Polynomial::from_coefficients(vec![0.12345, 2.0001]);
+    ///
+    /// assert_eq!(p.round_coefficients(2).coefficients(), &vec![0.12, 2.0]);
+    /// ```
+    pub fn round_coefficients(&self, decimals: u32) -> Polynomial<f64> {
+        let factor: f64 = 10f64.powi(decimals as i32);
+
+        self.map(|c| (c * factor).round() / factor)
+    }
+
+    /// Rounds every coefficient to the nearest integer.
+    /// Values outside the `i64` range saturate, and `NaN` becomes `0`.
+    pub fn round_to_integer(&self) -> Polynomial<i64> {
+        self.map(|c| c.round() as i64)
+    }
+}
+
+impl Polynomial<f64> {
+    /// Returns the polynomial of the given degree minimizing the squared error over `points`,
+    /// found by solving the normal equations `(VᵀV) c = Vᵀy` for the Vandermonde matrix `V`.
+    /// - Returns `None` if there are fewer than `degree + 1` points, or if the system is
+    ///   singular (e.g. too few distinct x-values).
+    /// - The normal equations square the condition number, so keep the degree low
+    ///   or the x-values near zero for high accuracy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let points: [(f64, f64); 3] = [(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)];
+    ///
+    /// let line: Polynomial<f64> = Polynomial::fit_least_squares(&points, 1).unwrap();
+    ///
+    /// assert!((line.evaluate(3.0) - 7.0).abs() < 1e-9);
+    /// ```
+    pub fn fit_least_squares(points: &[(f64, f64)], degree: usize) -> Option<Polynomial<f64>> {
+        let n: usize = degree + 1;
+
+        if points.len() < n {
+            return None;
+        }
+
+        let mut normal: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
+        let mut rhs: Vec<f64> = vec![0.0; n];
+
+        for &(x, y) in points {
+            let powers: Vec<f64> = (0..2 * n - 1).map(|k| x.powi(k as i32)).collect();
+
+            for (j, row) in normal.iter_mut().enumerate() {
+                for (k, entry) in row.iter_mut().enumerate() {
+                    *entry += powers[j + k];
+                }
+                rhs[j] += y * powers[j];
+            }
+        }
+
+        solve_linear_system(normal, rhs).map(Polynomial::from_coefficients)
+    }
+
+    /// Returns the Lagrange interpolating polynomial of degree below `points.len()`
+    /// passing through every point.
+    /// Returns `None` for an empty slice or duplicate x-values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<f64> = Polynomial::interpolate(&[(0.0, 1.0), (1.0, 2.0), (2.0, 5.0)]).unwrap();
+    ///
+    /// assert!(p.approx_eq(&Polynomial::from_coefficients(vec![1.0, 0.0, 1.0]), 1e-12));
+    /// ```
+    pub fn interpolate(points: &[(f64, f64)]) -> Option<Polynomial<f64>> {
+        if points.is_empty() || has_duplicate_x(points) {
+            return None;
+        }
+
+        let mut result: Polynomial<f64> = Polynomial::new();
+
+        for (i, &(xi, yi)) in points.iter().enumerate() {
+            let mut basis: Polynomial<f64> = Polynomial::from_coefficients(vec![1.0]);
+            let mut denominator: f64 = 1.0;
+
+            for (j, &(xj, _)) in points.iter().enumerate() {
+                if i != j {
+                    basis = basis * Polynomial::from_coefficients(vec![-xj, 1.0]);
+                    denominator *= xi - xj;
+                }
+            }
+
+            result = result + basis.map(|c| c * yi / denominator);
+        }
+
+        Some(result)
+    }
+
+    /// Returns the same interpolating polynomial as [`Polynomial::interpolate`], built from
+    /// Newton's divided differences `p(x) = c₀ + c₁(x - x₀) + c₂(x - x₀)(x - x₁) + ...`.
+    /// Each new point only adds one term, which makes the table convenient to extend.
+    /// Returns `None` for an empty slice or duplicate x-values.
+    pub fn interpolate_newton(points: &[(f64, f64)]) -> Option<Polynomial<f64>> {
+        if points.is_empty() || has_duplicate_x(points) {
+            return None;
+        }
+
+        let n: usize = points.len();
+        let mut differences: Vec<f64> = points.iter().map(|&(_, y)| y).collect();
+
+        for order in 1..n {
+            for i in (order..n).rev() {
+                differences[i] =
+                    (differences[i] - differences[i - 1]) / (points[i].0 - points[i - order].0);
+            }
+        }
+
+        let mut result: Polynomial<f64> = Polynomial::from_coefficients(vec![differences[n - 1]]);
+
+        for i in (0..n - 1).rev() {
+            result = result * Polynomial::from_coefficients(vec![-points[i].0, 1.0])
+                + Polynomial::from_coefficients(vec![differences[i]]);
+        }
+
+        Some(result)
+    }
 }
 
-impl<T> Default for Polynomial<T>
-where
-    T: Num + Clone,
-{
-    /// Creates a new polynomial with all coefficients set to zero.
-    /// - The degree of the polynomial is set to zero.
-    /// - The coefficients are stored in a vector of length one.
-    /// - The first element of the vector is the coefficient of the constant term.
-    /// Note: The degree of the zero polynomial is set to zero.
+impl Polynomial<f64> {
+    /// Returns `(p(x), p'(x))` from a single Horner sweep, carrying the derivative alongside the value.
+    /// This avoids building [`Polynomial::derivative`] in Newton-style iterations.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use vectra::polynomial::Polynomial;
     ///
-    /// let p: Polynomial<i32> = Polynomial::default();
+    /// // 1 + 4x + 5x^2
+    /// let p: Polynomial<f64> = Polynomial::from_coefficients(vec![1.0, 4.0, 5.0]);
     ///
-    /// assert_eq!(p.degree(), 0);
-    /// assert_eq!(p.coefficients(), &vec![0]);
+    /// assert_eq!(p.eval_and_deriv(2.0), (29.0, 24.0));
     /// ```
-    fn default() -> Self {
-        Self {
-            degree: 0,
-            coefficients: vec![T::zero(); 1],
-        }
+    pub fn eval_and_deriv(&self, x: f64) -> (f64, f64) {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold((0.0, 0.0), |(value, deriv), &c| {
+                (value * x + c, deriv * x + value)
+            })
     }
-}
 
-impl<T> Polynomial<T>
-where
-    T: Num + Clone,
-{
-    /// Creates a new polynomial with all coefficients set to zero.
+    /// Counts the distinct real roots in the half-open interval `(a, b]` using a Sturm sequence.
+    /// - The sequence is `p, p', -rem(p, p'), ...`, and the count is the drop in sign changes from `a` to `b`.
+    /// - A root exactly at `b` is counted, a root exactly at `a` is not.
+    /// - Multiple roots are counted once, and the zero polynomial has no counted roots.
+    /// - Remainder coefficients within the rounding error of their division are treated as zero,
+    ///   so repeated roots survive inexact coefficients. The count is still approximate for badly
+    ///   conditioned polynomials, e.g. with clustered roots next to a repeated one.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use vectra::polynomial::Polynomial;
     ///
-    /// let p: Polynomial<i32> = Polynomial::new();
+    /// let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-1.0, 0.0, 1.0]);
     ///
-    /// assert_eq!(p.degree(), 0);
-    /// assert_eq!(p.coefficients(), &vec![0]);
+    /// assert_eq!(p.count_real_roots(-2.0, 2.0), 2);
+    /// assert_eq!(p.count_real_roots(0.0, 2.0), 1);
     /// ```
-    pub fn new() -> Self {
-        Self::default()
+    pub fn count_real_roots(&self, a: f64, b: f64) -> usize {
+        if self.leading_degree().is_none() {
+            return 0;
+        }
+
+        let sequence: Vec<Polynomial<f64>> = self.sturm_sequence();
+        let changes = |x: f64| -> usize {
+            let signs: Vec<f64> = sequence
+                .iter()
+                .map(|p| p.evaluate(x))
+                .filter(|value| *value != 0.0)
+                .collect();
+
+            signs
+                .windows(2)
+                .filter(|pair| (pair[0] < 0.0) != (pair[1] < 0.0))
+                .count()
+        };
+
+        changes(a).saturating_sub(changes(b))
     }
-}
 
-impl<T> Polynomial<T> {
-    /// Creates a new polynomial from a vector of coefficients.
-    /// - The degree of the polynomial is set to the length of the vector minus one.
-    /// - The coefficients are stored in the vector in increasing order of degree.
-    /// - The first element of the vector is the coefficient of the lowest degree term (i.e., a constant).
-    /// - The last element of the vector is the coefficient of the highest degree term.
-    /// Any missing coefficients are set to zero.
+    /// Returns true if every pair of corresponding coefficients differs by at most `eps`.
+    /// The shorter coefficient vector is padded with zeros, so trailing zero terms don't affect the result.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use vectra::polynomial::Polynomial;
-    /// let p1: Polynomial<i32> = Polynomial::from_coefficients(vec![]);
-    /// let p2: Polynomial<i32> = Polynomial::from_coefficients(vec![1]);
-    /// let p3: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4]);
-    /// let p4: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
-    ///
-    /// assert_eq!(p1.degree(), 0);
-    /// assert_eq!(p1.coefficients(), &vec![0]);
-    ///
-    /// assert_eq!(p2.degree(), 0);
-    /// assert_eq!(p2.coefficients(), &vec![1]);
     ///
-    /// assert_eq!(p3.degree(), 1);
-    /// assert_eq!(p3.coefficients(), &vec![1, 4]);
+    /// let p1: Polynomial<f64> = Polynomial::from_coefficients(vec![0.1 + 0.2, 1.0]);
+    /// let p2: Polynomial<f64> = Polynomial::from_coefficients(vec![0.3, 1.0, 0.0]);
     ///
-    /// assert_eq!(p4.degree(), 2);
-    /// assert_eq!(p4.coefficients(), &vec![1, 4, 5]);
+    /// assert!(p1.approx_eq(&p2, 1e-12));
+    /// assert!(!p1.approx_eq(&p2, 0.0));
     /// ```
-    pub fn from_coefficients(coefficients: Vec<T>) -> Self
-    where
-        T: Num + Clone,
-    {
-        if coefficients.is_empty() {
-            return Self::new();
-        }
+    pub fn approx_eq(&self, other: &Polynomial<f64>, eps: f64) -> bool {
+        let len: usize = self.coefficients.len().max(other.coefficients.len());
 
-        let degree: usize = coefficients.len() - 1;
-        Self {
-            degree,
-            coefficients,
-        }
+        (0..len).all(|i| {
+            let a: f64 = self.coefficients.get(i).copied().unwrap_or(0.0);
+            let b: f64 = other.coefficients.get(i).copied().unwrap_or(0.0);
+
+            (a - b).abs() <= eps
+        })
     }
 
-    /// Returns the degree of the polynomial.
-    /// - The degree of a polynomial is the highest power of the variable in the polynomial.
-    /// - The degree of a constant polynomial is zero.
-    /// Note: The degree of the zero polynomial is set to zero.
+    /// Returns all roots (with multiplicity) for polynomials of degree 1 to 3,
+    /// using the quadratic formula and Cardano's method.
+    /// - A non-zero constant polynomial has no roots, so an empty vector is returned.
+    /// - Returns `None` for the zero polynomial and for degrees above 3, which need an iterative solver.
+    /// - Real roots of a cubic may carry a tiny imaginary part from rounding.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use vectra::polynomial::Polynomial;
     ///
-    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    /// // x^2 + 1
+    /// let p: Polynomial<f64> = Polynomial::from_coefficients(vec![1.0, 0.0, 1.0]);
     ///
-    /// assert_eq!(p.degree(), 2);
+    /// let roots = p.roots_closed_form().unwrap();
+    ///
+    /// assert_eq!(roots.len(), 2);
+    /// assert!(roots.iter().all(|z| z.re().abs() < 1e-12 && (z.im().abs() - 1.0).abs() < 1e-12));
     /// ```
-    pub fn degree(&self) -> usize {
-        self.degree
-    }
+    pub fn roots_closed_form(&self) -> Option<Vec<Complex<f64>>> {
+        let c = |degree: usize| NumComplex::new(self.coefficients[degree], 0.0);
 
-    pub fn coefficients(&self) -> &Vec<T> {
-        &self.coefficients
+        let roots: Vec<NumComplex<f64>> = match self.leading_degree()? {
+            0 => vec![],
+            1 => vec![-c(0) / c(1)],
+            2 => quadratic_roots(c(2), c(1), c(0)).to_vec(),
+            3 => cubic_roots(c(2) / c(3), c(1) / c(3), c(0) / c(3)).to_vec(),
+            _ => return None,
+        };
+
+        Some(roots.into_iter().map(Complex::from).collect())
     }
-}
 
-impl<T> Polynomial<T>
-where
-    T: Num + Clone,
-{
-    /// Returns the coefficient of the term with the given degree.
-    /// If the degree is greater than the degree of the polynomial, None is returned.
+    /// Finds a root in `[a, b]` by bisection, stopping once the bracket is narrower than `tol`.
+    /// - Returns `None` if `p(a)` and `p(b)` have the same sign, since no root is bracketed.
+    /// - An endpoint that is exactly a root is returned as is.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use vectra::polynomial::Polynomial;
     ///
-    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    /// let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-2.0, 1.0]);
     ///
-    /// assert_eq!(p.get_coefficient(0), Some(1));
-    /// assert_eq!(p.get_coefficient(1), Some(4));
-    /// assert_eq!(p.get_coefficient(2), Some(5));
-    /// assert_eq!(p.get_coefficient(3), None);
+    /// let root: f64 = p.root_bisection(0.0, 5.0, 1e-12).unwrap();
+    ///
+    /// assert!((root - 2.0).abs() < 1e-12);
+    /// assert!(p.root_bisection(3.0, 5.0, 1e-12).is_none());
     /// ```
-    pub fn get_coefficient(&self, degree: usize) -> Option<T> {
-        if degree > self.degree {
-            None
-        } else {
-            Some(self.coefficients[degree].clone())
+    pub fn root_bisection(&self, a: f64, b: f64, tol: f64) -> Option<f64> {
+        let (mut lo, mut hi) = (a.min(b), a.max(b));
+        let (mut f_lo, f_hi) = (self.evaluate(lo), self.evaluate(hi));
+
+        if f_lo == 0.0 {
+            return Some(lo);
+        }
+        if f_hi == 0.0 {
+            return Some(hi);
+        }
+        if (f_lo < 0.0) == (f_hi < 0.0) {
+            return None;
+        }
+
+        while hi - lo > tol {
+            let mid: f64 = lo + (hi - lo) / 2.0;
+            if mid <= lo || mid >= hi {
+                break;
+            }
+
+            let f_mid: f64 = self.evaluate(mid);
+            if f_mid == 0.0 {
+                return Some(mid);
+            }
+
+            if (f_mid < 0.0) == (f_lo < 0.0) {
+                lo = mid;
+                f_lo = f_mid;
+            } else {
+                hi = mid;
+            }
         }
+
+        Some(lo + (hi - lo) / 2.0)
     }
 
-    pub fn set_degree(&mut self, degree: usize) {
-        if degree > self.degree {
-            self.degree = degree;
-            self.coefficients.resize(degree + 1, T::zero());
+    fn sturm_sequence(&self) -> Vec<Polynomial<f64>> {
+        let mut sequence: Vec<Polynomial<f64>> = vec![self.clone(), self.derivative()];
+
+        while sequence[sequence.len() - 1].leading_degree().is_some() {
+            let len: usize = sequence.len();
+            let (dividend, divisor) = (&sequence[len - 2], &sequence[len - 1]);
+            let (quotient, mut remainder) = dividend.div_rem(divisor);
+
+            // Each remainder coefficient is `a_k - Σ q_i·d_j` over `i + j = k`. A value within the
+            // rounding error of that sum is indistinguishable from zero, and keeping it would
+            // split a repeated root into distinct ones.
+            let steps: f64 = (quotient.coefficients.len() + 1) as f64;
+            for (k, c) in remainder.coefficients.iter_mut().enumerate() {
+                let magnitude: f64 = dividend.coefficients[k].abs()
+                    + quotient
+                        .coefficients
+                        .iter()
+                        .take(k + 1)
+                        .zip(divisor.coefficients[..=k].iter().rev())
+                        .map(|(q, d)| (q * d).abs())
+                        .sum::<f64>();
+
+                if c.abs() <= 2.0 * steps * f64::EPSILON * magnitude {
+                    *c = 0.0;
+                }
+            }
+
+            if remainder.leading_degree().is_none() {
+                break;
+            }
+
+            sequence.push(Polynomial::from_coefficients(
+                remainder.coefficients.iter().map(|c| -c).collect(),
+            ));
         }
+
+        sequence
     }
+}
 
-    pub fn set_coefficient(&mut self, degree: usize, coefficient: T) {
-        self.set_degree(degree);
-        self.coefficients[degree] = coefficient;
+/// Solves `ax² + bx + c = 0` for `a ≠ 0`, avoiding cancellation by computing
+/// `q = -(b ± √(b² - 4ac)) / 2` with the sign matching `b`, then `x = q / a` and `x = c / q`.
+fn quadratic_roots(
+    a: NumComplex<f64>,
+    b: NumComplex<f64>,
+    c: NumComplex<f64>,
+) -> [NumComplex<f64>; 2] {
+    let mut sqrt_disc: NumComplex<f64> = (b * b - a * c * 4.0).sqrt();
+    if (b.conj() * sqrt_disc).re < 0.0 {
+        sqrt_disc = -sqrt_disc;
+    }
+
+    let q: NumComplex<f64> = -(b + sqrt_disc) / 2.0;
+    if q.norm_sqr() == 0.0 {
+        return [q, q];
+    }
+
+    [q / a, c / q]
+}
+
+/// Solves the monic cubic `x³ + ax² + bx + c = 0` with Cardano's method
+/// on the depressed cubic `t³ + pt + q` where `x = t - a/3`.
+fn cubic_roots(a: NumComplex<f64>, b: NumComplex<f64>, c: NumComplex<f64>) -> [NumComplex<f64>; 3] {
+    let shift: NumComplex<f64> = a / 3.0;
+    let p: NumComplex<f64> = b - a * a / 3.0;
+    let q: NumComplex<f64> = a * a * a * 2.0 / 27.0 - a * b / 3.0 + c;
+
+    let sqrt_disc: NumComplex<f64> = (q * q / 4.0 + p * p * p / 27.0).sqrt();
+    let (u_plus, u_minus) = (-q / 2.0 + sqrt_disc, -q / 2.0 - sqrt_disc);
+    let u: NumComplex<f64> = if u_plus.norm_sqr() >= u_minus.norm_sqr() {
+        u_plus
+    } else {
+        u_minus
+    };
+
+    if u.norm_sqr() == 0.0 {
+        return [-shift, -shift, -shift];
+    }
+
+    let omega: NumComplex<f64> = NumComplex::new(-0.5, 3f64.sqrt() / 2.0);
+    let mut cube_root: NumComplex<f64> = u.powf(1.0 / 3.0);
+    let mut roots: [NumComplex<f64>; 3] = [NumComplex::new(0.0, 0.0); 3];
+
+    for root in roots.iter_mut() {
+        *root = cube_root - p / (cube_root * 3.0) - shift;
+        cube_root *= omega;
+    }
+
+    roots
+}
+
+/// Returns true if two points share an x-value, so no interpolating polynomial exists.
+fn has_duplicate_x(points: &[(f64, f64)]) -> bool {
+    points
+        .iter()
+        .enumerate()
+        .any(|(i, (x, _))| points[i + 1..].iter().any(|(other, _)| other == x))
+}
+
+/// Solves the square system `a · x = b` by Gaussian elimination with partial pivoting.
+/// Returns `None` if a pivot vanishes relative to the largest entry of `a`.
+fn solve_linear_system(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n: usize = b.len();
+    let scale: f64 = a.iter().flatten().fold(0.0, |acc: f64, x| acc.max(x.abs()));
+
+    for col in 0..n {
+        let pivot: usize = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+
+        if a[pivot][col].is_nan() || a[pivot][col].abs() <= scale * n as f64 * f64::EPSILON {
+            return None;
+        }
+
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row: &[f64] = &upper[col];
+
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor: f64 = row[col] / pivot_row[col];
+
+            for (entry, pivot_entry) in row.iter_mut().zip(pivot_row).skip(col) {
+                *entry -= factor * pivot_entry;
+            }
+            b[col + 1 + offset] -= factor * b[col];
+        }
+    }
+
+    let mut x: Vec<f64> = vec![0.0; n];
+
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+
+    Some(x)
+}
+
+/// Multiplies two coefficient slices, keeping only the terms of degree below `n`.
+/// The result always has exactly `n` coefficients.
+fn truncated_product(a: &[f64], b: &[f64], n: usize) -> Vec<f64> {
+    let mut result: Vec<f64> = vec![0.0; n];
+
+    for (i, x) in a.iter().enumerate().take(n) {
+        for (j, y) in b.iter().enumerate().take(n - i) {
+            result[i + j] += x * y;
+        }
     }
+
+    result
 }
 
 impl<T> Add for Polynomial<T>
 where
-    T: Num + Clone + Default,
+    T: Num + Clone,
 {
     type Output = Self;
 
@@ -197,8 +1235,8 @@ where
         result.set_degree(self.degree.max(other.degree));
 
         for i in 0..=result.degree {
-            let c: T = self.get_coefficient(i).unwrap_or_default()
-                + other.get_coefficient(i).unwrap_or_default();
+            let c: T = self.get_coefficient(i).unwrap_or_else(T::zero)
+                + other.get_coefficient(i).unwrap_or_else(T::zero);
 
             result.set_coefficient(i, c);
         }
@@ -209,7 +1247,7 @@ where
 
 impl<T> Sub for Polynomial<T>
 where
-    T: Num + Clone + Default,
+    T: Num + Clone,
 {
     type Output = Self;
 
@@ -235,8 +1273,8 @@ where
         result.set_degree(self.degree.max(other.degree));
 
         for i in 0..=result.degree {
-            let c: T = self.get_coefficient(i).unwrap_or_default()
-                - other.get_coefficient(i).unwrap_or_default();
+            let c: T = self.get_coefficient(i).unwrap_or_else(T::zero)
+                - other.get_coefficient(i).unwrap_or_else(T::zero);
 
             result.set_coefficient(i, c);
         }
@@ -247,7 +1285,7 @@ where
 
 impl<T> Mul for Polynomial<T>
 where
-    T: Num + Clone + Default,
+    T: Num + Clone,
 {
     type Output = Self;
 
@@ -269,20 +1307,90 @@ where
     /// assert_eq!(p3.coefficients(), &vec![2, 16, 52, 92, 112, 116, 70]);
     /// ```
     fn mul(self, other: Self) -> Self {
-        let mut result: Polynomial<T> = Self::new();
-        result.set_degree(self.degree + other.degree);
+        Self::from_coefficients(signal::convolve(&self.coefficients, &other.coefficients))
+    }
+}
 
-        for i in 0..=self.degree {
-            for j in 0..=other.degree {
-                let c: T = result.get_coefficient(i + j).unwrap_or_default()
-                    + (self.get_coefficient(i).unwrap_or_default()
-                        * other.get_coefficient(j).unwrap_or_default());
+impl<T> Div<T> for Polynomial<T>
+where
+    T: Field,
+{
+    type Output = Self;
 
-                result.set_coefficient(i + j, c);
-            }
-        }
+    /// Divides every coefficient by a scalar.
+    /// For float coefficients, dividing by zero yields infinite or NaN coefficients rather than panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p1: Polynomial<f64> = Polynomial::from_coefficients(vec![0.0, 4.0, 2.0]);
+    ///
+    /// let p2: Polynomial<f64> = p1 / 2.0;
+    ///
+    /// assert_eq!(p2.degree(), 2);
+    /// assert_eq!(p2.coefficients(), &vec![0.0, 2.0, 1.0]);
+    /// ```
+    fn div(self, scalar: T) -> Self {
+        self.map(|c| c.clone() / scalar.clone())
+    }
+}
 
-        result
+impl<T> Rem for Polynomial<T>
+where
+    T: Field,
+{
+    type Output = Self;
+
+    /// Returns the remainder of dividing one polynomial by another, see [`Polynomial::div_rem`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p1: Polynomial<f64> = Polynomial::from_coefficients(vec![-1.0, 0.0, 1.0]);
+    /// let p2: Polynomial<f64> = Polynomial::from_coefficients(vec![-1.0, 1.0]);
+    ///
+    /// let p3: Polynomial<f64> = p1 % p2;
+    ///
+    /// assert_eq!(p3.degree(), 0);
+    /// assert_eq!(p3.coefficients(), &vec![0.0]);
+    /// ```
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(&other).1
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Debug,
+{
+    /// Returns the same `(degree, coefficient)` listing as `Debug`, but from the constant term up.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, 5]);
+    ///
+    /// assert_eq!(p.debug_ascending(), "[(0, 1), (1, 4), (2, 5)]");
+    /// ```
+    pub fn debug_ascending(&self) -> String {
+        let terms: Vec<String> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(degree, coefficient)| format!("({}, {:?})", degree, coefficient))
+            .collect();
+
+        format!("[{}]", terms.join(", "))
     }
 }
 
@@ -342,11 +1450,16 @@ where
     }
 }
 
-impl<T> Display for Polynomial<T>
+impl<T> Polynomial<T>
 where
     T: Numeric + Num + Clone + Display + Neg<Output = T> + PartialOrd + Zero,
 {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+    /// Formats the non-zero terms from the highest degree down, joining them with signs.
+    /// `term` renders the absolute value of a coefficient together with its degree.
+    fn format_terms<F>(&self, term: F) -> String
+    where
+        F: Fn(&T, usize) -> String,
+    {
         let mut formatted_string: String = String::new();
         let mut is_first_term: bool = true;
 
@@ -371,15 +1484,60 @@ where
                     formatted_string.push_str(sign);
                 }
 
-                let formatted: String = match degree {
-                    0 => format!("{}", coefficient),
-                    1 => format!("{}x", coefficient),
-                    _ => format!("{}x^{}", coefficient, degree),
-                };
+                formatted_string.push_str(&term(&coefficient, degree));
+            }
+        }
+
+        formatted_string
+    }
+
+    /// Renders the polynomial as LaTeX, grouping exponents with `^{}`.
+    /// - Unit coefficients are omitted on non-constant terms.
+    /// - The zero polynomial is rendered as `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::Polynomial;
+    ///
+    /// let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 4, -2, 5]);
+    ///
+    /// assert_eq!(p.to_latex(), "5x^{3} - 2x^{2} + 4x + 1");
+    /// assert_eq!(Polynomial::from_coefficients(vec![0, -1, 0, 1]).to_latex(), "x^{3} - x");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let latex: String = self.format_terms(|coefficient, degree| {
+            let coefficient: String = if degree > 0 && coefficient == &T::one() {
+                String::new()
+            } else {
+                format!("{}", coefficient)
+            };
 
-                formatted_string.push_str(&formatted);
+            match degree {
+                0 => coefficient,
+                1 => format!("{}x", coefficient),
+                _ => format!("{}x^{{{}}}", coefficient, degree),
             }
+        });
+
+        if latex.is_empty() {
+            "0".to_string()
+        } else {
+            latex
         }
+    }
+}
+
+impl<T> Display for Polynomial<T>
+where
+    T: Numeric + Num + Clone + Display + Neg<Output = T> + PartialOrd + Zero,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let formatted_string: String = self.format_terms(|coefficient, degree| match degree {
+            0 => format!("{}", coefficient),
+            1 => format!("{}x", coefficient),
+            _ => format!("{}x^{}", coefficient, degree),
+        });
 
         write!(f, "{}", formatted_string)
     }
@@ -0,0 +1,57 @@
+use super::super::traits::Conjugate;
+
+use num::Num;
+
+/// Returns the full discrete convolution of `a` and `b`, of length `a.len() + b.len() - 1`.
+/// - This is the same operation as multiplying polynomials with these coefficients.
+/// - If either input is empty, the result is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use vectra::polynomial::signal::convolve;
+///
+/// assert_eq!(convolve(&[1, 2, 3], &[0, 1, 0]), vec![0, 1, 2, 3, 0]);
+/// assert_eq!(convolve(&[1.0, 1.0], &[1.0, 1.0]), vec![1.0, 2.0, 1.0]);
+/// ```
+pub fn convolve<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Num + Clone,
+{
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let mut result: Vec<T> = vec![T::zero(); a.len() + b.len() - 1];
+
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            result[i + j] = result[i + j].clone() + x.clone() * y.clone();
+        }
+    }
+
+    result
+}
+
+/// Returns the full cross-correlation `r[k] = Σ a[n + k] · conj(b[n])`,
+/// computed as the convolution of `a` with `b` reversed and conjugated.
+/// - Index `i` of the result holds lag `k = i - (b.len() - 1)`, covering every overlap.
+/// - A positive lag means `a` is delayed relative to `b`.
+/// - If either input is empty, the result is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use vectra::polynomial::signal::correlate;
+///
+/// // Lags -1, 0, 1 and 2.
+/// assert_eq!(correlate(&[1, 2, 3], &[0, 1]), vec![1, 2, 3, 0]);
+/// ```
+pub fn correlate<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Num + Clone + Conjugate,
+{
+    let reversed: Vec<T> = b.iter().rev().map(Conjugate::conjugate).collect();
+
+    convolve(a, &reversed)
+}
@@ -0,0 +1,159 @@
+use super::Polynomial;
+
+use num::{pow, Num};
+use std::{
+    collections::BTreeMap,
+    ops::{Add, Mul},
+};
+
+/// A polynomial storing only its non-zero terms, keyed by degree.
+///
+/// Suited to high-degree polynomials with few terms such as `x^1000 + 1`, where the dense
+/// [`Polynomial`] would allocate every coefficient. Zero coefficients are never stored,
+/// so the zero polynomial has no terms.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparsePolynomial<T> {
+    terms: BTreeMap<usize, T>,
+}
+
+impl<T> SparsePolynomial<T>
+where
+    T: Num + Clone,
+{
+    pub fn new() -> Self {
+        Self {
+            terms: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a polynomial from `(degree, coefficient)` pairs,
+    /// summing repeated degrees and dropping zero coefficients.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::polynomial::sparse::SparsePolynomial;
+    ///
+    /// // x^1000 + 1
+    /// let p: SparsePolynomial<i64> = SparsePolynomial::from_terms(&[(1000, 1), (0, 1)]);
+    ///
+    /// assert_eq!(p.degree(), 1000);
+    /// assert_eq!(p.evaluate(1), 2);
+    /// assert_eq!(p.terms().count(), 2);
+    /// ```
+    pub fn from_terms(terms: &[(usize, T)]) -> Self {
+        let mut result: Self = Self::new();
+
+        for (degree, coefficient) in terms {
+            result.add_term(*degree, coefficient.clone());
+        }
+
+        result
+    }
+
+    /// Converts a dense polynomial, keeping only its non-zero coefficients.
+    pub fn from_dense(dense: &Polynomial<T>) -> Self {
+        let mut result: Self = Self::new();
+
+        for (degree, coefficient) in dense.coefficients().iter().enumerate() {
+            result.add_term(degree, coefficient.clone());
+        }
+
+        result
+    }
+
+    /// Converts to a dense polynomial whose degree is the highest stored degree.
+    pub fn to_dense(&self) -> Polynomial<T> {
+        let terms: Vec<(usize, T)> = self
+            .terms
+            .iter()
+            .map(|(degree, coefficient)| (*degree, coefficient.clone()))
+            .collect();
+
+        Polynomial::from_terms(&terms)
+    }
+
+    /// Returns the highest degree with a non-zero coefficient, or zero for the zero polynomial.
+    pub fn degree(&self) -> usize {
+        self.terms.keys().next_back().copied().unwrap_or(0)
+    }
+
+    /// Returns the non-zero `(degree, coefficient)` terms in ascending degree.
+    pub fn terms(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.terms
+            .iter()
+            .map(|(degree, coefficient)| (*degree, coefficient))
+    }
+
+    /// Evaluates the polynomial at `x`, raising `x` only across the gaps between stored degrees.
+    pub fn evaluate(&self, x: T) -> T {
+        let mut result: T = T::zero();
+        let mut power: T = T::one();
+        let mut last_degree: usize = 0;
+
+        for (degree, coefficient) in self.terms.iter() {
+            power = power * pow(x.clone(), degree - last_degree);
+            last_degree = *degree;
+
+            result = result + coefficient.clone() * power.clone();
+        }
+
+        result
+    }
+
+    fn add_term(&mut self, degree: usize, coefficient: T) {
+        let sum: T = match self.terms.remove(&degree) {
+            Some(existing) => existing + coefficient,
+            None => coefficient,
+        };
+
+        if !sum.is_zero() {
+            self.terms.insert(degree, sum);
+        }
+    }
+}
+
+impl<T> Default for SparsePolynomial<T>
+where
+    T: Num + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Add for SparsePolynomial<T>
+where
+    T: Num + Clone,
+{
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        for (degree, coefficient) in other.terms {
+            self.add_term(degree, coefficient);
+        }
+
+        self
+    }
+}
+
+impl<T> Mul for SparsePolynomial<T>
+where
+    T: Num + Clone,
+{
+    type Output = Self;
+
+    /// Multiplies term by term, costing `O(n·m)` in the number of stored terms
+    /// rather than in the degrees.
+    fn mul(self, other: Self) -> Self {
+        let mut result: Self = Self::new();
+
+        for (i, a) in self.terms.iter() {
+            for (j, b) in other.terms.iter() {
+                result.add_term(i + j, a.clone() * b.clone());
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,296 @@
+use crate::angles::Angle;
+
+#[test]
+fn add_angles() {
+    let angle: Angle = Angle::new_deg(30.0) + Angle::new_deg(60.0);
+
+    assert!((angle.get_deg() - 90.0).abs() < 1e-9);
+    assert!((angle.get_rad() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+}
+
+#[test]
+fn sub_neg_scale_angles() {
+    let angle: Angle = Angle::new_deg(90.0) - Angle::new_deg(30.0);
+    assert!((angle.get_deg() - 60.0).abs() < 1e-9);
+
+    let angle: Angle = -Angle::new_deg(45.0);
+    assert_eq!(angle.get_deg(), -45.0);
+
+    let angle: Angle = Angle::new_deg(45.0) * 2.0;
+    assert!((angle.get_deg() - 90.0).abs() < 1e-9);
+
+    let angle: Angle = Angle::new_deg(90.0) / 3.0;
+    assert!((angle.get_deg() - 30.0).abs() < 1e-9);
+}
+
+#[test]
+fn trig_and_inverses() {
+    let angle: Angle = Angle::new_deg(60.0);
+    assert!((angle.cos() - 0.5).abs() < 1e-12);
+    assert!((Angle::new_deg(45.0).tan() - 1.0).abs() < 1e-12);
+
+    let angle: Angle = Angle::from_atan2(1.0, -1.0);
+    assert!((angle.get_deg() - 135.0).abs() < 1e-9);
+    assert!((angle.get_rad() - 3.0 * std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+
+    assert!((Angle::from_asin(1.0).get_deg() - 90.0).abs() < 1e-9);
+    assert!((Angle::from_acos(1.0).get_deg()).abs() < 1e-9);
+    assert!((Angle::from_atan(1.0).get_deg() - 45.0).abs() < 1e-9);
+}
+
+#[test]
+fn normalize_angles() {
+    let angle: Angle = Angle::new_deg(370.0).normalized();
+    assert!((angle.get_deg() - 10.0).abs() < 1e-9);
+
+    let angle: Angle = Angle::new_deg(-10.0).normalized();
+    assert!((angle.get_deg() - 350.0).abs() < 1e-9);
+
+    let angle: Angle = Angle::new_deg(-10.0).normalized_signed();
+    assert!((angle.get_deg() + 10.0).abs() < 1e-9);
+
+    let angle: Angle = Angle::new_deg(190.0).normalized_signed();
+    assert!((angle.get_deg() + 170.0).abs() < 1e-9);
+
+    let angle: Angle = Angle::new_deg(180.0).normalized_signed();
+    assert!((angle.get_deg() - 180.0).abs() < 1e-9);
+
+    // `rem_euclid` alone would round these up to a full turn.
+    assert_eq!(Angle::new_rad(-1e-17).normalized().get_rad(), 0.0);
+    assert_eq!(Angle::new_rad(-1e-17).normalized_signed().get_rad(), 0.0);
+}
+
+#[test]
+fn compare_angles() {
+    assert!(Angle::new_deg(45.0) == Angle::new_deg(45.0));
+    assert!(Angle::new_deg(30.0) < Angle::new_deg(60.0));
+    assert!(Angle::new_rad(1.0) > Angle::new_deg(45.0));
+
+    let angle: Angle = Angle::new_deg(0.1) + Angle::new_deg(0.2);
+    assert!(angle.approx_eq(&Angle::new_deg(0.3), 1e-12));
+    assert!(!angle.approx_eq(&Angle::new_deg(0.4), 1e-12));
+}
+
+#[test]
+fn grad_and_turn_conversions() {
+    use crate::angles::AngleTypes;
+
+    let angle: Angle = Angle::new_grad(100.0);
+    assert!((angle.get_deg() - 90.0).abs() < 1e-9);
+    assert!((angle.get_rad() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    assert!((angle.get_turn() - 0.25).abs() < 1e-12);
+
+    let angle: Angle = Angle::new(AngleTypes::Turn(0.5));
+    assert!((angle.get_deg() - 180.0).abs() < 1e-9);
+    assert!((angle.get_rad() - std::f64::consts::PI).abs() < 1e-12);
+    assert!((angle.get_grad() - 200.0).abs() < 1e-9);
+
+    let angle: Angle = Angle::new(AngleTypes::Grad(400.0));
+    assert!((angle.get_turn() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn slerp_across_boundary() {
+    let angle: Angle = Angle::new_deg(350.0).slerp(&Angle::new_deg(10.0), 0.5);
+    assert!(angle.get_deg().abs() < 1e-9);
+
+    let angle: Angle = Angle::new_deg(10.0).slerp(&Angle::new_deg(350.0), 0.25);
+    assert!((angle.get_deg() - 5.0).abs() < 1e-9);
+
+    let angle: Angle = Angle::new_deg(20.0).slerp(&Angle::new_deg(80.0), 0.5);
+    assert!((angle.get_deg() - 50.0).abs() < 1e-9);
+}
+
+#[test]
+fn complement_and_supplement() {
+    assert_eq!(Angle::new_deg(30.0).complement().get_deg(), 60.0);
+    assert_eq!(Angle::new_deg(120.0).complement().get_deg(), -30.0);
+    assert_eq!(Angle::new_deg(30.0).supplement().get_deg(), 150.0);
+    assert_eq!(Angle::new_deg(200.0).supplement().get_deg(), -20.0);
+
+    let angle: Angle = Angle::new_deg(30.0).supplement();
+    assert!((angle.get_rad() - 5.0 * std::f64::consts::PI / 6.0).abs() < 1e-12);
+}
+
+#[test]
+fn display_precision() {
+    let angle: Angle = Angle::new_deg(45.0);
+
+    assert_eq!(format!("{}", angle), "45deg");
+    assert_eq!(format!("{:.2}", angle), "45.00deg");
+    assert_eq!(format!("{:.0}", Angle::new_deg(12.6)), "13deg");
+    assert_eq!(format!("{:.4}", angle.display_rad()), "0.7854rad");
+}
+
+#[test]
+fn sum_angles() {
+    let angles: Vec<Angle> = vec![
+        Angle::new_deg(30.0),
+        Angle::new_deg(30.0),
+        Angle::new_deg(30.0),
+    ];
+    let total: Angle = angles.into_iter().sum();
+    assert!((total.get_deg() - 90.0).abs() < 1e-9);
+
+    let total: Angle = Vec::<Angle>::new().into_iter().sum();
+    assert_eq!(total.get_deg(), 0.0);
+    assert_eq!(total.get_rad(), 0.0);
+}
+
+#[test]
+fn wrap_degrees() {
+    assert_eq!(Angle::wrap_deg(450.0).get_deg(), 90.0);
+    assert_eq!(Angle::wrap_deg(-90.0).get_deg(), 270.0);
+    assert_eq!(Angle::wrap_deg(360.0).get_deg(), 0.0);
+    assert_eq!(Angle::wrap_deg(-1e-17).get_deg(), 0.0);
+
+    assert_eq!(Angle::wrap_deg_signed(-90.0).get_deg(), -90.0);
+    assert_eq!(Angle::wrap_deg_signed(270.0).get_deg(), -90.0);
+    assert_eq!(Angle::wrap_deg_signed(-180.0).get_deg(), 180.0);
+    assert_eq!(Angle::wrap_deg_signed(-1e-17).get_deg(), 0.0);
+}
+
+#[test]
+fn angle_constants() {
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    assert_eq!(Angle::ZERO.get_rad(), 0.0);
+    assert_eq!(Angle::RIGHT.get_rad(), FRAC_PI_2);
+    assert_eq!(Angle::STRAIGHT.get_deg(), 180.0);
+    assert_eq!(Angle::STRAIGHT.get_rad(), PI);
+    assert_eq!(Angle::FULL.get_rad(), TAU);
+    assert!(Angle::RIGHT.approx_eq(&Angle::new_deg(90.0), 1e-15));
+}
+
+#[test]
+fn clone_and_copy_angles() {
+    use crate::angles::AngleTypes;
+
+    let angle: Angle = Angle::new_deg(30.0);
+    #[allow(clippy::clone_on_copy)]
+    let cloned: Angle = angle.clone();
+    let copied: Angle = angle;
+
+    assert_eq!(cloned.get_deg(), angle.get_deg());
+    assert_eq!(cloned.get_rad(), angle.get_rad());
+    assert!(copied == angle);
+
+    let kind: AngleTypes = AngleTypes::Deg(30.0);
+    assert!(kind == AngleTypes::Deg(30.0));
+    assert!(kind != AngleTypes::Rad(30.0));
+    assert!(Angle::new(kind) == Angle::new(kind));
+}
+
+#[test]
+fn angle_from_direction() {
+    use crate::vectors::vector2d::Vector2D;
+
+    let up: Angle = Angle::from_vector2d(0.0, 1.0);
+    assert_eq!(up.get_deg(), 90.0);
+    assert_eq!(up.get_rad(), std::f64::consts::FRAC_PI_2);
+
+    assert_eq!(Angle::from_vector2d(-1.0, 0.0).get_deg(), 180.0);
+    assert_eq!(Angle::from_vector2d(0.0, -2.0).get_deg(), -90.0);
+
+    let heading: Angle = Vector2D::new(1, 1).heading();
+    assert!((heading.get_deg() - 45.0).abs() < 1e-12);
+}
+
+#[test]
+fn accumulate_past_full_turn() {
+    let mut wheel: Angle = Angle::ZERO;
+
+    for _ in 0..4 {
+        wheel.accumulate(Angle::RIGHT);
+    }
+
+    assert_eq!(wheel.get_deg(), 360.0);
+    assert_eq!(wheel.total_turns(), 1.0);
+
+    wheel.accumulate(Angle::new_deg(540.0));
+    assert_eq!(wheel.total_turns(), 2.5);
+    assert!((wheel.get_rad() - 5.0 * std::f64::consts::PI).abs() < 1e-12);
+
+    wheel.accumulate(Angle::new_turn(-4.0));
+    assert_eq!(wheel.total_turns(), -1.5);
+}
+
+#[test]
+fn shortest_rotation_across_boundary() {
+    let near_full: Angle = Angle::new_deg(350.0);
+    let past_zero: Angle = Angle::new_deg(10.0);
+
+    assert!((near_full.shortest_to(&past_zero).get_deg() - 20.0).abs() < 1e-9);
+    assert!((past_zero.shortest_to(&near_full).get_deg() + 20.0).abs() < 1e-9);
+
+    assert!(
+        (Angle::new_deg(-170.0)
+            .shortest_to(&Angle::new_deg(170.0))
+            .get_deg()
+            + 20.0)
+            .abs()
+            < 1e-9
+    );
+    assert!(
+        (Angle::new_deg(30.0)
+            .shortest_to(&Angle::new_deg(750.0))
+            .get_deg())
+        .abs()
+            < 1e-9
+    );
+
+    // Exactly opposite headings resolve to +180°.
+    assert!((Angle::ZERO.shortest_to(&Angle::STRAIGHT).get_deg() - 180.0).abs() < 1e-9);
+}
+
+#[test]
+fn dms_round_trip() {
+    let angle: Angle = Angle::from_dms(45, 30, 15.0);
+    assert!((angle.get_deg() - (45.0 + 30.0 / 60.0 + 15.0 / 3600.0)).abs() < 1e-12);
+
+    let (deg, min, sec) = angle.to_dms();
+    assert_eq!((deg, min), (45, 30));
+    assert!((sec - 15.0).abs() < 1e-6);
+
+    let (deg, min, sec) = Angle::from_dms(-120, 5, 30.5).to_dms();
+    assert_eq!((deg, min), (-120, 5));
+    assert!((sec - 30.5).abs() < 1e-6);
+
+    // The sign of angles between -1° and 0° lives on the seconds.
+    let small: Angle = Angle::new_deg(-0.5);
+    let (deg, min, sec) = small.to_dms();
+    assert_eq!((deg, min), (0, 30));
+    assert!(sec.is_sign_negative());
+    assert_eq!(Angle::from_dms(deg, min, sec).get_deg(), -0.5);
+}
+
+#[test]
+fn sort_by_normalized_position() {
+    use std::cmp::Ordering;
+
+    let mut headings: Vec<Angle> = vec![
+        Angle::new_deg(370.0),
+        Angle::new_deg(-90.0),
+        Angle::new_rad(std::f64::consts::PI),
+        Angle::new_deg(5.0),
+        Angle::new_turn(2.0),
+        Angle::new_deg(-350.0),
+    ];
+
+    headings.sort_by(Angle::cmp_normalized);
+
+    let positions: Vec<f64> = headings
+        .iter()
+        .map(|angle| angle.normalized().get_deg().round())
+        .collect();
+    assert_eq!(positions, vec![0.0, 5.0, 10.0, 10.0, 180.0, 270.0]);
+
+    assert_eq!(
+        Angle::new_deg(370.0).cmp_normalized(&Angle::new_deg(-350.0)),
+        Ordering::Equal
+    );
+    assert_eq!(
+        Angle::new_rad(-1e-15).cmp_normalized(&Angle::ZERO),
+        Ordering::Equal
+    );
+}
@@ -0,0 +1,176 @@
+use crate::nums::complex::Complex;
+
+#[test]
+fn sub_complex() {
+    let z: Complex<i32> = Complex::new(5, 3) - Complex::new(2, 7);
+
+    assert_eq!((z.re(), z.im()), (3, -4));
+}
+
+#[test]
+fn mul_complex() {
+    let z: Complex<i32> = Complex::new(1, 2) * Complex::new(3, 4);
+
+    assert_eq!((z.re(), z.im()), (-5, 10));
+}
+
+#[test]
+fn div_complex() {
+    let z: Complex<f64> = Complex::new(-5.0, 10.0) / Complex::new(3.0, 4.0);
+
+    assert!((z.re() - 1.0).abs() < 1e-12);
+    assert!((z.im() - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn conjugate_complex() {
+    let z: Complex<i32> = Complex::new(3, 4).conjugate();
+
+    assert_eq!((z.re(), z.im()), (3, -4));
+}
+
+#[test]
+fn modulus_and_argument() {
+    let z: Complex<f64> = Complex::new(0.0, 1.0);
+
+    assert!((z.modulus() - 1.0).abs() < 1e-12);
+    assert!((z.argument().get_deg() - 90.0).abs() < 1e-9);
+
+    let z: Complex<i32> = Complex::new(3, -4);
+
+    assert_eq!(z.modulus(), 5.0);
+    assert!(z.argument().get_deg() < 0.0);
+}
+
+#[test]
+fn polar_round_trip() {
+    let z: Complex<f64> = Complex::new(-1.5, 2.5);
+    let (r, theta) = z.to_polar();
+    let w: Complex<f64> = Complex::from_polar(r, theta);
+
+    assert!((w.re() - z.re()).abs() < 1e-12);
+    assert!((w.im() - z.im()).abs() < 1e-12);
+
+    let z: Complex<f64> = Complex::from_polar(2.0, crate::angles::Angle::new_deg(90.0));
+
+    assert!(z.re().abs() < 1e-12);
+    assert!((z.im() - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn debug_complex() {
+    assert_eq!(
+        format!("{:?}", Complex::new(1.5, -2.0)),
+        "Complex { re: 1.5, im: -2.0 }"
+    );
+}
+
+#[test]
+fn neg_eq_and_scale_complex() {
+    assert_eq!(-Complex::new(1, -2), Complex::new(-1, 2));
+    assert_ne!(Complex::new(1, 2), Complex::new(2, 1));
+    assert_eq!(Complex::new(1, -2) * 3, Complex::new(3, -6));
+}
+
+#[test]
+fn num_complex_round_trip() {
+    let z: Complex<i32> = Complex::from((3, -4));
+    let inner: num::Complex<i32> = z.into_inner();
+
+    assert_eq!(inner, num::Complex::new(3, -4));
+    assert_eq!(Complex::from(inner), Complex::new(3, -4));
+}
+
+#[test]
+fn transcendental_complex() {
+    let z: Complex<f64> = Complex::new(0.0, std::f64::consts::PI).exp();
+    assert!((z.re() + 1.0).abs() < 1e-12);
+    assert!(z.im().abs() < 1e-12);
+
+    let z: Complex<f64> = Complex::new(-1.0, 0.0).ln();
+    assert!(z.re().abs() < 1e-12);
+    assert!((z.im() - std::f64::consts::PI).abs() < 1e-12);
+
+    let z: Complex<f64> = Complex::new(-4.0, 0.0).sqrt();
+    assert!(z.re().abs() < 1e-12);
+    assert!((z.im() - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn powers_complex() {
+    let i: Complex<f64> = Complex::new(0.0, 1.0);
+
+    assert_eq!(i.powi(2), Complex::new(-1.0, 0.0));
+    assert_eq!(i.powi(0), Complex::new(1.0, 0.0));
+    assert_eq!(Complex::new(1.0, 1.0).powi(-2), Complex::new(0.0, -0.5));
+
+    let z: Complex<f64> = Complex::new(1.0, 2.0).powf(3.0);
+    let w: Complex<f64> = Complex::new(1.0, 2.0).powi(3);
+    assert!((z.re() - w.re()).abs() < 1e-9);
+    assert!((z.im() - w.im()).abs() < 1e-9);
+}
+
+#[test]
+fn assign_operators_complex() {
+    let mut z: Complex<i32> = Complex::new(0, 0);
+    for _ in 0..3 {
+        z += Complex::new(0, 1);
+    }
+    assert_eq!(z, Complex::new(0, 3));
+
+    z -= Complex::new(1, 1);
+    assert_eq!(z, Complex::new(-1, 2));
+
+    z *= Complex::new(3, 4);
+    assert_eq!(z, Complex::new(-11, 2));
+}
+
+#[test]
+fn zero_and_one() {
+    use num::{One, Zero};
+
+    assert_eq!(Complex::zero() + Complex::new(1, 2), Complex::new(1, 2));
+    assert_eq!(
+        Complex::<f64>::one() * Complex::new(1.5, -2.0),
+        Complex::new(1.5, -2.0)
+    );
+    assert!(Complex::<i32>::zero().is_zero());
+    assert!(!Complex::new(0, 1).is_zero());
+    assert!(Complex::<f64>::one().is_one());
+}
+
+#[test]
+fn complex_polynomial_coefficients() {
+    use crate::polynomial::Polynomial;
+    use num::Num;
+
+    let p: Polynomial<Complex<f64>> =
+        Polynomial::from_coefficients(vec![Complex::new(1.0, -1.0), Complex::new(0.0, 2.0)]);
+    let q: Polynomial<Complex<f64>> =
+        Polynomial::from_terms(&[(0, Complex::new(2.0, 0.5)), (2, Complex::new(3.0, 0.0))]);
+
+    let sum: Polynomial<Complex<f64>> = p.clone() + q.clone();
+    assert_eq!(
+        sum.coefficients(),
+        &vec![
+            Complex::new(3.0, -0.5),
+            Complex::new(0.0, 2.0),
+            Complex::new(3.0, 0.0)
+        ]
+    );
+
+    let i: Complex<f64> = Complex::new(0.0, 1.0);
+    // p(i) = -1 - i and q(i) = -1 + 0.5i
+    assert_eq!(p.evaluate(i), Complex::new(-1.0, -1.0));
+    assert_eq!((p * q).evaluate(i), Complex::new(1.5, 0.5));
+
+    assert_eq!(
+        Complex::<i32>::from_str_radix("3-4i", 10),
+        Ok(Complex::new(3, -4))
+    );
+    assert_eq!(Complex::new(7, 3) % Complex::new(2, 0), Complex::new(1, 1));
+    assert_eq!(
+        Complex::new(10, 5) / Complex::new(1, 2),
+        Complex::new(4, -3)
+    );
+}
@@ -0,0 +1,60 @@
+use crate::vectors::curves::bezier_point;
+use crate::vectors::vector3d::Vector3D;
+
+#[test]
+fn bezier_endpoints_and_midpoint() {
+    let control: [Vector3D<f64>; 4] = [
+        Vector3D::new(0.0, 0.0, 0.0),
+        Vector3D::new(1.0, 3.0, -1.0),
+        Vector3D::new(4.0, 3.0, 2.0),
+        Vector3D::new(5.0, 0.0, 1.0),
+    ];
+
+    assert_eq!(
+        bezier_point(&control, 0.0).to_array(),
+        control[0].to_array()
+    );
+    assert_eq!(
+        bezier_point(&control, 1.0).to_array(),
+        control[3].to_array()
+    );
+
+    // Cubic Bernstein weights at t = 0.5 are 1/8, 3/8, 3/8, 1/8.
+    assert_eq!(bezier_point(&control, 0.5).to_array(), [2.5, 2.25, 0.5]);
+
+    let single: [Vector3D<f64>; 1] = [Vector3D::new(1.0, 2.0, 3.0)];
+    assert_eq!(bezier_point(&single, 0.7).to_array(), [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn catmull_rom_through_waypoints() {
+    use crate::vectors::curves::catmull_rom;
+
+    let waypoints: [Vector3D<f64>; 5] = [
+        Vector3D::new(0.0, 0.0, 0.0),
+        Vector3D::new(1.0, 2.0, 0.0),
+        Vector3D::new(3.0, 2.5, 1.0),
+        Vector3D::new(4.0, 0.0, 1.0),
+        Vector3D::new(6.0, -1.0, 0.0),
+    ];
+
+    for (i, waypoint) in waypoints.iter().enumerate() {
+        let on_curve: Vector3D<f64> = catmull_rom(&waypoints, i as f64);
+
+        assert!((on_curve - *waypoint).magnitude() < 1e-12);
+    }
+
+    // Out-of-range parameters clamp to the ends.
+    assert!((catmull_rom(&waypoints, -1.0) - waypoints[0]).magnitude() < 1e-12);
+    assert!((catmull_rom(&waypoints, 9.0) - waypoints[4]).magnitude() < 1e-12);
+
+    // Evenly spaced collinear points give a straight path.
+    let line: [Vector3D<f64>; 3] = [
+        Vector3D::new(0.0, 0.0, 0.0),
+        Vector3D::new(1.0, 1.0, 1.0),
+        Vector3D::new(2.0, 2.0, 2.0),
+    ];
+    let mid: Vector3D<f64> = catmull_rom(&line, 1.5);
+    assert!((mid.x - mid.y).abs() < 1e-12 && (mid.y - mid.z).abs() < 1e-12);
+    assert!(mid.x > 1.0 && mid.x < 2.0);
+}
@@ -0,0 +1,35 @@
+use num::Num;
+use std::ops::Add;
+
+crate::bundle_traits!(Plain, Clone, Copy);
+crate::bundle_traits!(pub Scalar<T>, Num, Clone where T: Copy);
+crate::bundle_traits!(Summable<'a, T>, Add<&'a T, Output = T> where T: 'a);
+
+fn is_plain<P: Plain>(_: P) {}
+
+fn double<T: Copy, S: Scalar<T>>(value: S) -> S {
+    value.clone() + value
+}
+
+fn add_ref<'a, T: 'a, S: Summable<'a, T>>(lhs: S, rhs: &'a T) -> T {
+    lhs + rhs
+}
+
+#[test]
+fn bundle_traits_generics() {
+    is_plain(1u8);
+
+    assert_eq!(double::<u8, i32>(21), 42);
+    assert_eq!(add_ref(1, &2), 3);
+}
+
+#[test]
+fn time_it_forms() {
+    let timed = crate::time_it!({ (1..=10).sum::<i32>() });
+    assert_eq!(*timed, 55);
+    assert_eq!(timed.into_inner(), 55);
+
+    let (value, elapsed) = crate::time_it!(@tuple { (1..=10).product::<i32>() });
+    assert_eq!(value, 3628800);
+    assert!(elapsed.as_secs() < 60);
+}
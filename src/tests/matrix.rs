@@ -0,0 +1,106 @@
+use crate::angles::Angle;
+use crate::matrix::mat3::Matrix3;
+use crate::vectors::vector3d::Vector3D;
+
+#[test]
+fn identity_leaves_vector_unchanged() {
+    let v: Vector3D<f64> = Vector3D::new(1.5, -2.0, 7.25);
+
+    assert_eq!(Matrix3::identity().mul_vector(&v).to_array(), v.to_array());
+    assert_eq!(
+        Matrix3::<i32>::identity()
+            .mul_vector(&Vector3D::new(4, 5, 6))
+            .to_array(),
+        [4, 5, 6]
+    );
+}
+
+#[test]
+fn rotation_matrix_matches_rotate_around() {
+    let axis: Vector3D<f64> = Vector3D::new(0.0, 1.0, 1.0);
+    let angle: Angle = Angle::new_deg(120.0);
+    let m: Matrix3<f64> = Matrix3::from_rows(Vector3D::rotation_matrix(&axis, angle));
+    let v: Vector3D<f64> = Vector3D::new(3.0, -1.0, 0.5);
+
+    let by_matrix: [f64; 3] = m.mul_vector(&v).to_array();
+    let by_rodrigues: [f64; 3] = v.rotate_around(&axis, angle).to_array();
+
+    for (a, b) in by_matrix.iter().zip(by_rodrigues) {
+        assert!((a - b).abs() < 1e-12);
+    }
+
+    // Rotations are orthogonal with determinant 1.
+    assert!((m.determinant() - 1.0).abs() < 1e-12);
+    let product: Matrix3<f64> = m * m.transpose();
+    for i in 0..3 {
+        for j in 0..3 {
+            let expected: f64 = if i == j { 1.0 } else { 0.0 };
+            assert!((product.rows[i][j] - expected).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn multiply_transpose_determinant() {
+    let a: Matrix3<i32> = Matrix3::from_rows([[1, 2, 3], [0, 1, 4], [5, 6, 0]]);
+    let b: Matrix3<i32> = Matrix3::from_rows([[2, 0, 0], [0, 1, 0], [1, 0, 1]]);
+
+    assert_eq!(a.determinant(), 1);
+    assert_eq!(a.transpose().rows, [[1, 0, 5], [2, 1, 6], [3, 4, 0]]);
+    assert_eq!((a * b).rows, [[5, 2, 3], [4, 1, 4], [10, 6, 0]]);
+
+    let v: Vector3D<i32> = Vector3D::new(1, -1, 2);
+    assert_eq!(
+        (a * b).mul_vector(&v).to_array(),
+        a.mul_vector(&b.mul_vector(&v)).to_array()
+    );
+}
+
+#[test]
+fn inverse() {
+    let m: Matrix3<f64> = Matrix3::from_rows([[2.0, -1.0, 0.0], [1.0, 3.0, 2.0], [0.5, 0.0, 4.0]]);
+    let product: Matrix3<f64> = m * m.inverse().unwrap();
+    let identity: Matrix3<f64> = Matrix3::identity();
+
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((product.rows[i][j] - identity.rows[i][j]).abs() < 1e-12);
+        }
+    }
+
+    let integer: Matrix3<i32> = Matrix3::from_rows([[1, 2, 3], [0, 1, 4], [5, 6, 0]]);
+    assert_eq!(
+        integer.inverse().unwrap().rows,
+        [[-24.0, 18.0, 5.0], [20.0, -15.0, -4.0], [-5.0, 4.0, 1.0]]
+    );
+
+    let singular: Matrix3<f64> =
+        Matrix3::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert!(singular.inverse().is_none());
+    assert!(Matrix3::from_rows([[0.0; 3]; 3]).inverse().is_none());
+
+    // A tiny determinant does not make a well-conditioned matrix singular.
+    let scaled: Matrix3<f64> =
+        Matrix3::from_rows([[1e-10, 0.0, 0.0], [0.0, 1e-10, 0.0], [0.0, 0.0, 1.0]]);
+    assert_eq!(
+        scaled.inverse().unwrap().rows,
+        [[1e10, 0.0, 0.0], [0.0, 1e10, 0.0], [0.0, 0.0, 1.0]]
+    );
+}
+
+#[test]
+fn solve_linear_system() {
+    // x + 2y - z = 2, 2x - y + 3z = 9, 3x + y + z = 8 has the solution (1, 2, 3).
+    let m: Matrix3<f64> = Matrix3::from_rows([[1.0, 2.0, -1.0], [2.0, -1.0, 3.0], [3.0, 1.0, 1.0]]);
+    let rhs: Vector3D<f64> = Vector3D::new(2.0, 9.0, 8.0);
+
+    let x: Vector3D<f64> = m.solve(&rhs).unwrap();
+    let residual: Vector3D<f64> = m.mul_vector(&x) - rhs;
+
+    assert!(residual.magnitude() < 1e-12);
+    assert!((x - Vector3D::new(1.0, 2.0, 3.0)).magnitude() < 1e-12);
+
+    let singular: Matrix3<f64> =
+        Matrix3::from_rows([[1.0, 1.0, 0.0], [2.0, 2.0, 0.0], [0.0, 0.0, 1.0]]);
+    assert!(singular.solve(&rhs).is_none());
+}
@@ -1,2 +1,26 @@
 #[cfg(test)]
+mod angles;
+#[cfg(test)]
+mod complex;
+#[cfg(test)]
+mod curves;
+#[cfg(test)]
+mod macros;
+#[cfg(test)]
+mod matrix;
+#[cfg(test)]
 mod polynomial;
+#[cfg(test)]
+mod quantity;
+#[cfg(test)]
+mod quaternion;
+#[cfg(test)]
+mod signal;
+#[cfg(test)]
+mod traits;
+#[cfg(test)]
+mod units;
+#[cfg(test)]
+mod vector2d;
+#[cfg(test)]
+mod vector3d;
@@ -1,3 +1,5 @@
+use crate::polynomial::Polynomial;
+
 #[test]
 fn test() {}
 
@@ -6,3 +8,530 @@ fn test2() {}
 
 #[test]
 fn test3() {}
+
+#[test]
+fn evaluate_matrix() {
+    // p(x) = x^2 - 5x - 2, the characteristic polynomial of A, so p(A) = 0.
+    let p: Polynomial<i32> = Polynomial::from_coefficients(vec![-2, -5, 1]);
+    let a: Vec<Vec<i32>> = vec![vec![1, 2], vec![3, 4]];
+
+    assert_eq!(p.evaluate_matrix(&a), vec![vec![0, 0], vec![0, 0]]);
+
+    let constant: Polynomial<i32> = Polynomial::from_coefficients(vec![3]);
+    assert_eq!(constant.evaluate_matrix(&a), vec![vec![3, 0], vec![0, 3]]);
+}
+
+#[test]
+#[should_panic]
+fn evaluate_matrix_non_square() {
+    let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 1]);
+    p.evaluate_matrix(&[vec![1, 2]]);
+}
+
+#[test]
+fn content_and_primitive_part() {
+    let p: Polynomial<i64> = Polynomial::from_coefficients(vec![-9, 0, -3, 0]);
+
+    assert_eq!(p.content(), -3);
+    assert_eq!(p.primitive_part().coefficients(), &vec![3, 0, 1, 0]);
+
+    let zero: Polynomial<i64> = Polynomial::from_coefficients(vec![0, 0]);
+
+    assert_eq!(zero.content(), 0);
+    assert_eq!(zero.primitive_part().coefficients(), &vec![0, 0]);
+}
+
+#[test]
+fn inverse_series() {
+    // 1 / (1 + 2x + 3x^2), checked by multiplying back.
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![1.0, 2.0, 3.0]);
+    let inverse: Polynomial<f64> = p.inverse_series(7).unwrap();
+
+    assert_eq!(inverse.degree(), 6);
+
+    let product: Polynomial<f64> = p * inverse;
+    for (degree, coefficient) in product.coefficients().iter().enumerate().take(7) {
+        let expected: f64 = if degree == 0 { 1.0 } else { 0.0 };
+        assert!((coefficient - expected).abs() < 1e-9);
+    }
+
+    let halved: Polynomial<f64> = Polynomial::from_coefficients(vec![2.0])
+        .inverse_series(3)
+        .unwrap();
+    assert_eq!(halved.coefficients(), &vec![0.5, 0.0, 0.0]);
+}
+
+#[test]
+fn count_real_roots() {
+    // (x - 1)(x + 1) = x^2 - 1
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-1.0, 0.0, 1.0]);
+
+    assert_eq!(p.count_real_roots(-2.0, 2.0), 2);
+    assert_eq!(p.count_real_roots(-1.0, 1.0), 1);
+    assert_eq!(p.count_real_roots(2.0, 5.0), 0);
+
+    // (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-6.0, 11.0, -6.0, 1.0]);
+
+    assert_eq!(p.count_real_roots(0.0, 10.0), 3);
+    assert_eq!(p.count_real_roots(1.5, 2.5), 1);
+
+    // x^2 + 1 has no real roots
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![1.0, 0.0, 1.0]);
+
+    assert_eq!(p.count_real_roots(-10.0, 10.0), 0);
+
+    // (x - 0.1)^2 (x - 0.3) has inexact coefficients, but the double root is still counted once.
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-0.1, 1.0])
+        * Polynomial::from_coefficients(vec![-0.1, 1.0])
+        * Polynomial::from_coefficients(vec![-0.3, 1.0]);
+
+    assert_eq!(p.count_real_roots(-5.0, 5.0), 2);
+
+    // A tiny but exact constant term still separates two roots.
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-1e-17, 0.0, 1.0]);
+
+    assert_eq!(p.count_real_roots(-1.0, 1.0), 2);
+    assert_eq!(p.count_real_roots(0.0, 1.0), 1);
+}
+
+#[test]
+fn root_bisection() {
+    // x^2 - 2
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-2.0, 0.0, 1.0]);
+
+    let root: f64 = p.root_bisection(0.0, 2.0, 1e-10).unwrap();
+    assert!((root - 2f64.sqrt()).abs() < 1e-10);
+
+    let root: f64 = p.root_bisection(-2.0, 0.0, 1e-10).unwrap();
+    assert!((root + 2f64.sqrt()).abs() < 1e-10);
+
+    assert!(p.root_bisection(-1.0, 1.0, 1e-10).is_none());
+    assert_eq!(
+        Polynomial::from_coefficients(vec![0.0, 1.0]).root_bisection(0.0, 1.0, 1e-10),
+        Some(0.0)
+    );
+}
+
+#[test]
+#[should_panic]
+fn rem_by_zero_polynomial() {
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![1.0, 1.0]);
+
+    let _ = p % Polynomial::from_coefficients(vec![0.0, 0.0]);
+}
+
+#[test]
+fn scalar_div_complex() {
+    use num::Complex;
+
+    let p: Polynomial<Complex<f64>> =
+        Polynomial::from_coefficients(vec![Complex::new(0.0, 2.0), Complex::new(4.0, 0.0)]);
+
+    assert_eq!(
+        (p / Complex::new(0.0, 2.0)).coefficients(),
+        &vec![Complex::new(1.0, 0.0), Complex::new(0.0, -2.0)]
+    );
+}
+
+#[test]
+fn rem_with_complex_coefficients() {
+    use num::Complex;
+
+    let i: Complex<f64> = Complex::new(0.0, 1.0);
+    let one: Complex<f64> = Complex::new(1.0, 0.0);
+    let zero: Complex<f64> = Complex::new(0.0, 0.0);
+
+    // x^2 + 1 = (x + i)(x - i)
+    let p: Polynomial<Complex<f64>> = Polynomial::from_coefficients(vec![one, zero, one]);
+    let d: Polynomial<Complex<f64>> = Polynomial::from_coefficients(vec![-i, one]);
+
+    let (q, r) = p.clone().div_rem(&d);
+    assert_eq!(q.coefficients(), &vec![i, one]);
+    assert!(r.coefficients().iter().all(|c| *c == zero));
+
+    // x^2 + 1 = (x / 2i)(2i) + 1
+    let r: Polynomial<Complex<f64>> = p % Polynomial::from_coefficients(vec![zero, i * 2.0]);
+    assert_eq!(r.coefficients(), &vec![one]);
+}
+
+#[test]
+fn display_and_latex() {
+    let p: Polynomial<i32> = Polynomial::from_coefficients(vec![-1, 0, 1, -3]);
+
+    assert_eq!(format!("{}", p), "- 3x^3 + 1x^2 - 1");
+    assert_eq!(p.to_latex(), "- 3x^{3} + x^{2} - 1");
+    assert_eq!(Polynomial::<f64>::new().to_latex(), "0");
+
+    let mut coefficients: Vec<f64> = vec![0.0; 12];
+    coefficients[0] = 0.5;
+    coefficients[11] = 2.0;
+    assert_eq!(
+        Polynomial::from_coefficients(coefficients).to_latex(),
+        "2x^{11} + 0.5"
+    );
+}
+
+#[test]
+fn approx_eq_after_float_arithmetic() {
+    // Lagrange and Newton interpolation build the same polynomial through different rounding.
+    let points: Vec<(f64, f64)> = [-1.5, -0.3, 0.2, 0.7, 1.9]
+        .iter()
+        .map(|&x: &f64| (x, x.sin()))
+        .collect();
+    let lagrange: Polynomial<f64> = Polynomial::interpolate(&points).unwrap();
+    let newton: Polynomial<f64> = Polynomial::interpolate_newton(&points).unwrap();
+
+    assert!(lagrange.approx_eq(&newton, 1e-9));
+    assert!(!lagrange.approx_eq(&(newton + Polynomial::from_coefficients(vec![1e-6])), 1e-9));
+
+    // A truncated series inverse times the original is 1 up to rounding, below the cut-off degree.
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![3.0, 0.1, -0.7, 0.3]);
+    let product: Polynomial<f64> = (p.inverse_series(6).unwrap() * p).truncate(5);
+
+    assert!(product.approx_eq(&Polynomial::from_coefficients(vec![1.0]), 1e-9));
+
+    // The shorter vector is padded with zeros, so the longer one's tail must itself be within `eps`.
+    let short: Polynomial<f64> = Polynomial::from_coefficients(vec![0.1 + 0.2, 1.0]);
+
+    assert!(short.approx_eq(
+        &Polynomial::from_coefficients(vec![0.3, 1.0, 0.0, 1e-12]),
+        1e-9
+    ));
+    assert!(!short.approx_eq(
+        &Polynomial::from_coefficients(vec![0.3, 1.0, 0.0, 1e-3]),
+        1e-9
+    ));
+    assert!(!Polynomial::from_coefficients(vec![0.3, 1.0, 0.0, 1e-3]).approx_eq(&short, 1e-9));
+}
+
+#[test]
+fn roots_closed_form() {
+    let close = |roots: &[crate::nums::complex::Complex<f64>], re: f64, im: f64| {
+        roots
+            .iter()
+            .any(|z| (z.re() - re).abs() < 1e-9 && (z.im() - im).abs() < 1e-9)
+    };
+
+    // (x - 1)(x^2 + 1) = x^3 - x^2 + x - 1
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-1.0, 1.0, -1.0, 1.0]);
+    let roots = p.roots_closed_form().unwrap();
+
+    assert_eq!(roots.len(), 3);
+    assert!(close(&roots, 1.0, 0.0));
+    assert!(close(&roots, 0.0, 1.0));
+    assert!(close(&roots, 0.0, -1.0));
+
+    // (x - 1)(x - 2)(x - 3) with a trailing zero coefficient
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-6.0, 11.0, -6.0, 1.0, 0.0]);
+    let roots = p.roots_closed_form().unwrap();
+
+    assert!(close(&roots, 1.0, 0.0) && close(&roots, 2.0, 0.0) && close(&roots, 3.0, 0.0));
+
+    // (x - 2)^3
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-8.0, 12.0, -6.0, 1.0]);
+    assert!(p
+        .roots_closed_form()
+        .unwrap()
+        .iter()
+        .all(|z| (z.re() - 2.0).abs() < 1e-9));
+
+    // x^2 - 3x + 2
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![2.0, -3.0, 1.0]);
+    let roots = p.roots_closed_form().unwrap();
+    assert!(close(&roots, 1.0, 0.0) && close(&roots, 2.0, 0.0));
+
+    assert_eq!(
+        Polynomial::from_coefficients(vec![3.0, 2.0])
+            .roots_closed_form()
+            .unwrap()[0],
+        crate::nums::complex::Complex::new(-1.5, 0.0)
+    );
+    assert!(Polynomial::from_coefficients(vec![5.0])
+        .roots_closed_form()
+        .unwrap()
+        .is_empty());
+    assert!(Polynomial::<f64>::new().roots_closed_form().is_none());
+    assert!(Polynomial::from_coefficients(vec![1.0; 5])
+        .roots_closed_form()
+        .is_none());
+}
+
+#[test]
+fn complex_coefficient_arithmetic() {
+    use num::Complex;
+
+    let p: Polynomial<Complex<f64>> =
+        Polynomial::from_coefficients(vec![Complex::new(1.0, 2.0), Complex::new(0.0, 1.0)]);
+    let q: Polynomial<Complex<f64>> = Polynomial::from_coefficients(vec![
+        Complex::new(3.0, -1.0),
+        Complex::new(1.0, 0.0),
+        Complex::new(0.0, 0.5),
+    ]);
+
+    assert_eq!(
+        (p.clone() + q.clone()).coefficients(),
+        &vec![
+            Complex::new(4.0, 1.0),
+            Complex::new(1.0, 1.0),
+            Complex::new(0.0, 0.5)
+        ]
+    );
+    assert_eq!(
+        (q.clone() - p.clone()).coefficients(),
+        &vec![
+            Complex::new(2.0, -3.0),
+            Complex::new(1.0, -1.0),
+            Complex::new(0.0, 0.5)
+        ]
+    );
+
+    let z: Complex<f64> = Complex::new(0.5, -2.0);
+    assert_eq!(
+        (p.clone() * q.clone()).evaluate(z),
+        p.evaluate(z) * q.evaluate(z)
+    );
+}
+
+#[test]
+fn eval_and_deriv() {
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-2.5, 0.0, 3.0, -1.0, 0.25]);
+    let dp: Polynomial<f64> = p.derivative();
+
+    for x in [-3.0, -0.5, 0.0, 1.0, 2.75] {
+        let (value, deriv) = p.eval_and_deriv(x);
+
+        assert!((value - p.evaluate(x)).abs() < 1e-12);
+        assert!((deriv - dp.evaluate(x)).abs() < 1e-12);
+    }
+
+    assert_eq!(
+        Polynomial::from_coefficients(vec![4.0]).eval_and_deriv(7.0),
+        (4.0, 0.0)
+    );
+}
+
+#[test]
+fn rounding_coefficients() {
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![1.0, 2.0, 3.0]);
+    let q: Polynomial<f64> = Polynomial::from_coefficients(vec![-1.0, 1.0]);
+    let noisy: Polynomial<f64> =
+        (p * q).map(|c| c + 3e-9) - Polynomial::from_coefficients(vec![1e-9; 4]);
+
+    assert_ne!(noisy.coefficients(), &vec![-1.0, -1.0, -1.0, 3.0]);
+    assert_eq!(
+        noisy.round_to_integer().coefficients(),
+        &vec![-1, -1, -1, 3]
+    );
+    assert_eq!(
+        noisy.round_coefficients(6).coefficients(),
+        &vec![-1.0, -1.0, -1.0, 3.0]
+    );
+    assert_eq!(
+        Polynomial::from_coefficients(vec![-2.5, 0.4999])
+            .round_to_integer()
+            .coefficients(),
+        &vec![-3, 0]
+    );
+}
+
+#[test]
+fn from_sparse_terms() {
+    let p: Polynomial<i32> = Polynomial::from_terms(&[(2, 3), (0, -1), (2, 4), (1, 0)]);
+
+    assert_eq!(p.degree(), 2);
+    assert_eq!(p.coefficients(), &vec![-1, 0, 7]);
+    assert_eq!(Polynomial::<i32>::from_terms(&[]).coefficients(), &vec![0]);
+}
+
+#[test]
+fn sparse_multiplication_matches_dense() {
+    use crate::polynomial::sparse::SparsePolynomial;
+
+    // (x^50 + 2x^3 - 1)(x^40 - 2x^3)
+    let p: SparsePolynomial<i64> = SparsePolynomial::from_terms(&[(50, 1), (3, 2), (0, -1)]);
+    let q: SparsePolynomial<i64> = SparsePolynomial::from_terms(&[(40, 1), (3, -2)]);
+    let product: SparsePolynomial<i64> = p.clone() * q.clone();
+
+    assert_eq!(product.degree(), 90);
+    assert_eq!(
+        product.terms().collect::<Vec<_>>(),
+        vec![(3, &2), (6, &-4), (40, &-1), (43, &2), (53, &-2), (90, &1)]
+    );
+
+    let dense: Polynomial<i64> = product.to_dense();
+    let expected: Polynomial<i64> = p.to_dense() * q.to_dense();
+
+    assert_eq!(dense.coefficients(), expected.coefficients());
+    assert_eq!(SparsePolynomial::from_dense(&expected), product);
+    assert_eq!(product.evaluate(-1), dense.evaluate(-1));
+
+    let sum: SparsePolynomial<i64> = p + SparsePolynomial::from_terms(&[(3, -2), (1, 5)]);
+    assert_eq!(
+        sum.terms().collect::<Vec<_>>(),
+        vec![(0, &-1), (1, &5), (50, &1)]
+    );
+}
+
+#[test]
+fn debug_orderings() {
+    let p: Polynomial<f64> = Polynomial::from_coefficients(vec![-1.0, 0.0, 2.5]);
+
+    assert_eq!(format!("{:?}", p), "[(2, 2.5), (1, 0.0), (0, -1.0)]");
+    assert_eq!(p.debug_ascending(), "[(0, -1.0), (1, 0.0), (2, 2.5)]");
+    assert_eq!(Polynomial::<i32>::new().debug_ascending(), "[(0, 0)]");
+}
+
+#[test]
+fn evaluate_mod_prime() {
+    // x^3 + 2x + 5 over Z/13: at x = 4 this is 77 = 12 mod 13
+    let p: Polynomial<u64> = Polynomial::from_coefficients(vec![5, 2, 0, 1]);
+
+    assert_eq!(p.evaluate_mod(4, 13), 12);
+    assert_eq!(p.evaluate_mod(4 + 13 * 1000, 13), 12);
+
+    // Coefficients and x near u64::MAX would overflow plain Horner.
+    let big: Polynomial<u64> = Polynomial::from_coefficients(vec![u64::MAX, u64::MAX, u64::MAX]);
+    let m: u64 = 1_000_000_007;
+    let (c, x): (u128, u128) = ((u64::MAX % m) as u128, (u64::MAX % m) as u128);
+    let expected: u128 = (c + c * x % m as u128 + c * (x * x % m as u128)) % m as u128;
+
+    assert_eq!(big.evaluate_mod(u64::MAX, m) as u128, expected);
+}
+
+#[test]
+fn checked_integer_arithmetic() {
+    let p: Polynomial<i32> = Polynomial::from_coefficients(vec![1, 2, 3]);
+    let q: Polynomial<i32> = Polynomial::from_coefficients(vec![4, 5]);
+
+    assert_eq!(
+        p.checked_mul(&q).unwrap().coefficients(),
+        (p.clone() * q.clone()).coefficients()
+    );
+    assert_eq!(
+        p.checked_add(&q).unwrap().coefficients(),
+        (p + q).coefficients()
+    );
+
+    let large: Polynomial<i32> = Polynomial::from_coefficients(vec![50_000, 50_000]);
+    assert!(large.checked_mul(&large).is_none());
+
+    // Each product fits, but 2 * 40_000^2 overflows when summed into the x coefficient.
+    let medium: Polynomial<i32> = Polynomial::from_coefficients(vec![40_000, 40_000]);
+    assert!(medium.checked_mul(&medium).is_none());
+
+    let min: Polynomial<i32> = Polynomial::from_coefficients(vec![i32::MIN]);
+    assert!(min.checked_add(&min).is_none());
+    assert!(min
+        .checked_mul(&Polynomial::from_coefficients(vec![-1]))
+        .is_none());
+}
+
+#[test]
+fn least_squares_fit() {
+    // Points near y = 2x + 1 with small alternating noise.
+    let points: Vec<(f64, f64)> = (0..10)
+        .map(|i| {
+            let x: f64 = i as f64 * 0.5;
+            let noise: f64 = if i % 2 == 0 { 0.01 } else { -0.01 };
+
+            (x, 2.0 * x + 1.0 + noise)
+        })
+        .collect();
+
+    let line: Polynomial<f64> = Polynomial::fit_least_squares(&points, 1).unwrap();
+    assert_eq!(line.degree(), 1);
+    assert!((line.coefficients()[0] - 1.0).abs() < 0.02);
+    assert!((line.coefficients()[1] - 2.0).abs() < 0.01);
+
+    // An exact quadratic is recovered.
+    let quadratic: Polynomial<f64> = Polynomial::from_coefficients(vec![0.5, -1.0, 3.0]);
+    let samples: Vec<(f64, f64)> = [-2.0, -1.0, 0.0, 0.5, 1.0, 3.0]
+        .iter()
+        .map(|&x| (x, quadratic.evaluate(x)))
+        .collect();
+    assert!(Polynomial::fit_least_squares(&samples, 2)
+        .unwrap()
+        .approx_eq(&quadratic, 1e-9));
+
+    assert!(Polynomial::fit_least_squares(&points[..2], 2).is_none());
+    assert!(Polynomial::fit_least_squares(&[(1.0, 2.0), (1.0, 3.0), (1.0, 4.0)], 1).is_none());
+}
+
+#[test]
+fn fit_least_squares_wide_ranges() {
+    // Exact data over ranges where the normal matrix has a tiny determinant relative to its
+    // largest entry, but every pivot is well away from zero.
+    for (coefficients, xs) in [
+        (vec![2.0, -1.0, 0.5, 0.25], 0..=100),
+        (vec![1.0, -2.0, 0.5, 0.1, -0.01], 0..=20),
+        (vec![3.0, 1.0, -1.0, 0.5, 0.2, -0.05], 0..=10),
+    ] {
+        let expected: Polynomial<f64> = Polynomial::from_coefficients(coefficients);
+        let degree: usize = expected.degree();
+        let points: Vec<(f64, f64)> = xs
+            .map(|x| (x as f64, expected.evaluate(x as f64)))
+            .collect();
+
+        let fit: Polynomial<f64> = Polynomial::fit_least_squares(&points, degree).unwrap();
+
+        assert!(fit.approx_eq(&expected, 1e-6), "degree {}", degree);
+    }
+}
+
+#[test]
+fn newton_matches_lagrange_interpolation() {
+    let points: [(f64, f64); 5] = [
+        (-2.0, 3.5),
+        (-0.5, 1.0),
+        (0.0, -1.0),
+        (1.5, 2.25),
+        (4.0, 0.0),
+    ];
+
+    let newton: Polynomial<f64> = Polynomial::interpolate_newton(&points).unwrap();
+    let lagrange: Polynomial<f64> = Polynomial::interpolate(&points).unwrap();
+
+    assert_eq!(newton.degree(), 4);
+    assert!(newton.approx_eq(&lagrange, 1e-9));
+
+    for (x, y) in points {
+        assert!((newton.evaluate(x) - y).abs() < 1e-9);
+    }
+
+    assert!(Polynomial::interpolate_newton(&[(1.0, 2.0), (1.0, 3.0)]).is_none());
+    assert!(Polynomial::interpolate(&[(1.0, 2.0), (1.0, 3.0)]).is_none());
+    assert!(Polynomial::interpolate_newton(&[]).is_none());
+    assert_eq!(
+        Polynomial::interpolate_newton(&[(5.0, 7.0)])
+            .unwrap()
+            .coefficients(),
+        &vec![7.0]
+    );
+}
+
+#[test]
+fn in_place_arithmetic_matches_operators() {
+    let step: Polynomial<i64> = Polynomial::from_coefficients(vec![1, -2, 1]);
+    let term: Polynomial<i64> = Polynomial::from_coefficients(vec![3, 0, 0, 1]);
+
+    let mut in_place: Polynomial<i64> = Polynomial::from_coefficients(vec![1]);
+    let mut owned: Polynomial<i64> = Polynomial::from_coefficients(vec![1]);
+
+    for _ in 0..20 {
+        in_place.add_assign_ref(&term);
+        in_place.mul_assign_ref(&step);
+
+        owned = (owned + term.clone()) * step.clone();
+    }
+
+    assert_eq!(in_place.degree(), owned.degree());
+    assert_eq!(in_place.coefficients(), owned.coefficients());
+
+    let mut p: Polynomial<i64> = Polynomial::from_coefficients(vec![1, 2, 3, 4]);
+    p.add_assign_ref(&Polynomial::from_coefficients(vec![1]));
+    assert_eq!(p.coefficients(), &vec![2, 2, 3, 4]);
+
+    p.mul_assign_ref(&Polynomial::new());
+    assert!(p.is_zero());
+}
@@ -0,0 +1,72 @@
+use crate::miscs::{quantity::Quantity, units::Unit};
+
+fn metres(value: f64) -> Quantity<f64> {
+    Quantity::new(value, Unit::from_powers([1, 0, 0, 0, 0, 0, 0]))
+}
+
+fn seconds(value: f64) -> Quantity<f64> {
+    Quantity::new(value, Unit::from_powers([0, 0, 1, 0, 0, 0, 0]))
+}
+
+#[test]
+fn add_and_sub_quantities() {
+    let sum: Quantity<f64> = (metres(5.0) + metres(3.0)).unwrap();
+
+    assert_eq!(sum.value, 8.0);
+    assert_eq!(sum.unit.powers(), [1, 0, 0, 0, 0, 0, 0]);
+
+    assert!((metres(5.0) + seconds(3.0)).is_err());
+    assert!((metres(5.0) - seconds(3.0)).is_err());
+    assert_eq!((metres(5.0) - metres(3.0)).unwrap().value, 2.0);
+}
+
+#[test]
+fn mul_and_div_quantities() {
+    let velocity: Quantity<f64> = metres(10.0) / seconds(2.0);
+
+    assert_eq!(velocity.value, 5.0);
+    assert_eq!(velocity.unit.powers(), [1, 0, -1, 0, 0, 0, 0]);
+
+    let area: Quantity<f64> = metres(2.0) * metres(3.0);
+
+    assert_eq!(format!("{}", area), "6 L²");
+}
+
+#[test]
+fn add_and_sub_mixed_prefixes() {
+    use crate::miscs::units::{BaseUnit, UnitPrefix};
+
+    let kilometre = |value: f64| {
+        let mut quantity: Quantity<f64> = metres(value);
+        quantity.unit.set_prefix(BaseUnit::Length, UnitPrefix::Kilo);
+        quantity
+    };
+
+    let sum: Quantity<f64> = (kilometre(1.0) + metres(500.0)).unwrap();
+    assert!((sum.value - 1.5).abs() < 1e-12);
+    assert_eq!(format!("{}", sum.unit), "Lk");
+
+    let sum: Quantity<f64> = (metres(500.0) + kilometre(1.0)).unwrap();
+    assert!((sum.value - 1500.0).abs() < 1e-9);
+    assert_eq!(format!("{}", sum.unit), "L");
+
+    let difference: Quantity<f64> = (kilometre(1.0) - metres(500.0)).unwrap();
+    assert!((difference.value - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn mul_and_div_mixed_prefixes() {
+    use crate::miscs::units::{BaseUnit, UnitPrefix};
+
+    let mut kilometre: Quantity<f64> = metres(1.0);
+    kilometre
+        .unit
+        .set_prefix(BaseUnit::Length, UnitPrefix::Kilo);
+
+    let ratio: Quantity<f64> = Quantity::new(kilometre.value, kilometre.unit) / metres(1.0);
+    assert_eq!(ratio.value, 1000.0);
+    assert_eq!(ratio.unit.powers(), [0; 7]);
+
+    let area: Quantity<f64> = kilometre * metres(1.0);
+    assert!((area.value * area.unit.to_base_factor() - 1000.0).abs() < 1e-9);
+}
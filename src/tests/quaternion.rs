@@ -0,0 +1,81 @@
+use crate::angles::Angle;
+use crate::nums::quaternion::Quaternion;
+use crate::vectors::vector3d::Vector3D;
+
+fn assert_vec_close(a: &Vector3D<f64>, b: &Vector3D<f64>) {
+    assert!((a.x - b.x).abs() < 1e-12, "{:?} != {:?}", a, b);
+    assert!((a.y - b.y).abs() < 1e-12, "{:?} != {:?}", a, b);
+    assert!((a.z - b.z).abs() < 1e-12, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn composition_equals_combined_rotation() {
+    let z_axis: Vector3D<f64> = Vector3D::new(0.0, 0.0, 1.0);
+    let first: Quaternion<f64> = Quaternion::from_axis_angle(&z_axis, Angle::new_deg(30.0));
+    let second: Quaternion<f64> = Quaternion::from_axis_angle(&z_axis, Angle::new_deg(60.0));
+    let combined: Quaternion<f64> = Quaternion::from_axis_angle(&z_axis, Angle::RIGHT);
+
+    let composed: Quaternion<f64> = second * first;
+    assert!((composed.w - combined.w).abs() < 1e-12);
+    assert!((composed.z - combined.z).abs() < 1e-12);
+
+    // Rotations around different axes: applying `a` then `b` equals `b * a`.
+    let a: Quaternion<f64> =
+        Quaternion::from_axis_angle(&Vector3D::new(1.0, 0.0, 0.0), Angle::new_deg(40.0));
+    let b: Quaternion<f64> =
+        Quaternion::from_axis_angle(&Vector3D::new(0.0, 1.0, 1.0), Angle::new_deg(-75.0));
+    let v: Vector3D<f64> = Vector3D::new(0.3, -1.2, 2.0);
+
+    assert_vec_close(&(b * a).rotate(&v), &b.rotate(&a.rotate(&v)));
+}
+
+#[test]
+fn matches_rotate_around() {
+    let axis: Vector3D<f64> = Vector3D::new(1.0, 2.0, -2.0);
+    let angle: Angle = Angle::new_deg(73.0);
+    let q: Quaternion<f64> = Quaternion::from_axis_angle(&axis, angle);
+    let v: Vector3D<f64> = Vector3D::new(-1.0, 0.5, 4.0);
+
+    assert_vec_close(&q.rotate(&v), &v.rotate_around(&axis, angle));
+    assert_vec_close(&q.conjugate().rotate(&q.rotate(&v)), &v);
+}
+
+#[test]
+fn rotation_matrix_round_trip() {
+    let axis: Vector3D<f64> = Vector3D::new(-3.0, 1.0, 0.5);
+
+    for deg in [10.0, 95.0, 179.0, 260.0] {
+        let q: Quaternion<f64> = Quaternion::from_axis_angle(&axis, Angle::new_deg(deg));
+        let q: Quaternion<f64> = if q.w < 0.0 {
+            Quaternion::new(-q.w, -q.x, -q.y, -q.z)
+        } else {
+            q
+        };
+        let m: [[f64; 3]; 3] = q.to_rotation_matrix();
+        let expected: [[f64; 3]; 3] = Vector3D::rotation_matrix(&axis, Angle::new_deg(deg));
+
+        for row in 0..3 {
+            for col in 0..3 {
+                assert!((m[row][col] - expected[row][col]).abs() < 1e-12);
+            }
+        }
+
+        let back: Quaternion<f64> = Quaternion::from_rotation_matrix(&m);
+        assert!((back.w - q.w).abs() < 1e-12);
+        assert!((back.x - q.x).abs() < 1e-12);
+        assert!((back.y - q.y).abs() < 1e-12);
+        assert!((back.z - q.z).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn normalize() {
+    let q: Quaternion<f64> = Quaternion::new(1.0, 1.0, 1.0, 1.0).normalize();
+
+    assert_eq!(q, Quaternion::new(0.5, 0.5, 0.5, 0.5));
+    assert!((q.norm() - 1.0).abs() < 1e-12);
+    assert_eq!(
+        Quaternion::new(1, 2, 3, 4).conjugate(),
+        Quaternion::new(1, -2, -3, -4)
+    );
+}
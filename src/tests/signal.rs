@@ -0,0 +1,57 @@
+use crate::polynomial::signal::convolve;
+
+#[test]
+fn convolve_kernels() {
+    // Box blur kernel with a difference kernel, computed by hand.
+    let blur: [i32; 3] = [1, 1, 1];
+    let diff: [i32; 2] = [1, -1];
+
+    assert_eq!(convolve(&blur, &diff), vec![1, 0, 0, -1]);
+    assert_eq!(convolve(&diff, &blur), vec![1, 0, 0, -1]);
+
+    let a: [f64; 3] = [0.5, 2.0, -1.0];
+    let b: [f64; 2] = [4.0, 0.25];
+    assert_eq!(convolve(&a, &b), vec![2.0, 8.125, -3.5, -0.25]);
+
+    assert_eq!(convolve(&[3], &[7]), vec![21]);
+    assert!(convolve::<i32>(&[], &[1, 2]).is_empty());
+}
+
+#[test]
+fn correlate_finds_lag() {
+    use crate::polynomial::signal::correlate;
+
+    let signal: [f64; 6] = [1.0, 3.0, -2.0, 0.5, 0.0, 0.0];
+    let shifted: [f64; 6] = [0.0, 0.0, 1.0, 3.0, -2.0, 0.5];
+
+    let r: Vec<f64> = correlate(&shifted, &signal);
+    let best: usize = (0..r.len())
+        .max_by(|&i, &j| r[i].partial_cmp(&r[j]).unwrap())
+        .unwrap();
+
+    assert_eq!(r.len(), 11);
+    assert_eq!(best as isize - (signal.len() as isize - 1), 2);
+    assert_eq!(r[best], 14.25);
+}
+
+#[test]
+fn correlate_conjugates_complex() {
+    use crate::polynomial::signal::correlate;
+    use num::Complex;
+
+    let z: Complex<i32> = Complex::new(1, 2);
+    let r: Vec<Complex<i32>> = correlate(&[z], &[z]);
+
+    // Zero-lag autocorrelation is the energy |z|^2, which is real.
+    assert_eq!(r, vec![Complex::new(5, 0)]);
+}
+
+#[test]
+fn correlate_conjugates_crate_complex() {
+    use crate::nums::complex::Complex;
+    use crate::polynomial::signal::correlate;
+
+    let z: Complex<i32> = Complex::new(1, 2);
+
+    assert_eq!(correlate(&[z], &[z]), vec![Complex::new(5, 0)]);
+}
@@ -0,0 +1,81 @@
+use crate::traits::Numeric;
+
+fn sum<T: Numeric + Copy>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |acc, &value| acc + value)
+}
+
+fn mean<T: Numeric + Copy>(values: &[T]) -> f64 {
+    sum(values).to_f64() / values.len() as f64
+}
+
+#[test]
+fn generic_numeric_sum() {
+    assert_eq!(sum(&[1, 2, 3, 4]), 10);
+    assert_eq!(sum(&[1u8, 2, 3]), 6u8);
+    assert_eq!(sum(&[0.5, 0.25]), 0.75);
+    assert_eq!(sum::<i64>(&[]), 0);
+    assert_eq!(mean(&[1u16, 2, 3, 4]), 2.5);
+    assert_eq!(<i32 as num::One>::one().to_f64(), 1.0);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Seconds(f32);
+
+impl std::ops::Add for Seconds {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Seconds(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::Mul for Seconds {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Seconds(self.0 * rhs.0)
+    }
+}
+
+impl num::Zero for Seconds {
+    fn zero() -> Self {
+        Seconds(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+}
+
+impl num::One for Seconds {
+    fn one() -> Self {
+        Seconds(1.0)
+    }
+}
+
+crate::impl_numeric!(Seconds => |value: &Seconds| value.0 as f64);
+
+#[test]
+fn user_numeric_type() {
+    assert_eq!(sum(&[Seconds(1.5), Seconds(2.0)]), Seconds(3.5));
+    assert_eq!(mean(&[Seconds(1.0), Seconds(2.0)]), 1.5);
+}
+
+#[test]
+fn conjugate_crate_types() {
+    use crate::nums::{complex::Complex, quaternion::Quaternion};
+    use crate::traits::Conjugate;
+
+    fn conjugate_all<T: Conjugate>(values: &[T]) -> Vec<T> {
+        values.iter().map(Conjugate::conjugate).collect()
+    }
+
+    assert_eq!(
+        conjugate_all(&[Complex::new(3, 4), Complex::new(-1, -2)]),
+        vec![Complex::new(3, -4), Complex::new(-1, 2)]
+    );
+    assert_eq!(
+        conjugate_all(&[Quaternion::new(1.0, 2.0, -3.0, 4.0)]),
+        vec![Quaternion::new(1.0, -2.0, 3.0, -4.0)]
+    );
+}
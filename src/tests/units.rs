@@ -0,0 +1,245 @@
+use crate::miscs::units::Unit;
+
+#[test]
+fn multiply_units() {
+    let length: Unit = Unit::from_powers([1, 0, 0, 0, 0, 0, 0]);
+    let area: Unit = length * Unit::from_powers([1, 0, 0, 0, 0, 0, 0]);
+
+    assert_eq!(area.powers(), [2, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn divide_units() {
+    let length: Unit = Unit::from_powers([1, 0, 0, 0, 0, 0, 0]);
+    let time: Unit = Unit::from_powers([0, 0, 1, 0, 0, 0, 0]);
+    let velocity: Unit = length / time;
+
+    assert_eq!(velocity.powers(), [1, 0, -1, 0, 0, 0, 0]);
+
+    let dimensionless: Unit = velocity / Unit::from_powers([1, 0, -1, 0, 0, 0, 0]);
+
+    assert_eq!(dimensionless.powers(), [0; 7]);
+}
+
+#[test]
+fn combine_mixed_prefixes() {
+    use crate::miscs::units::{BaseUnit, UnitPrefix};
+
+    let metre: Unit = Unit::from_powers([1, 0, 0, 0, 0, 0, 0]);
+    let mut kilometre: Unit = metre;
+    kilometre.set_prefix(BaseUnit::Length, UnitPrefix::Kilo);
+    let mut millimetre: Unit = metre;
+    millimetre.set_prefix(BaseUnit::Length, UnitPrefix::Milli);
+
+    let (ratio, factor) = kilometre.div_scaled(metre);
+    assert!(ratio == Unit::new());
+    assert_eq!(factor, 1e3);
+
+    let (area, factor) = kilometre.mul_scaled(metre);
+    assert_eq!(format!("{}", area), "Lk²");
+    assert_eq!(factor * area.to_base_factor(), 1e3);
+
+    let (area, factor) = kilometre.mul_scaled(millimetre);
+    assert!(area == metre.pow(2));
+    assert_eq!(factor, 1.0);
+
+    let (area, factor) = kilometre.mul_scaled(kilometre);
+    assert_eq!(area.to_base_factor(), 1e6);
+    assert_eq!(factor, 1.0);
+}
+
+#[test]
+#[should_panic]
+fn combine_overflow() {
+    let _ = Unit::from_powers([127, 0, 0, 0, 0, 0, 0]) * Unit::from_powers([1, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+#[should_panic]
+fn combine_negated_power_overflow() {
+    let _ = Unit::new() / Unit::from_powers([-128, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn display_unit() {
+    let force: Unit = Unit::from_powers([1, 1, 0, 0, 0, 0, 0]);
+
+    assert_eq!(format!("{}", force), "LM");
+    assert_eq!(
+        format!("{}", Unit::from_powers([2, 0, 0, 0, 0, 0, 3])),
+        "L²J³"
+    );
+    assert_eq!(
+        format!("{:?}", Unit::from_powers([0, 0, 0, 0, 1, 0, 0])),
+        "Unit(Θ)"
+    );
+    assert_eq!(format!("{}", Unit::new()), "");
+}
+
+#[test]
+fn display_negative_and_multi_digit_powers() {
+    let acceleration: Unit = Unit::from_powers([1, 0, -2, 0, 0, 0, 0]);
+
+    assert_eq!(format!("{}", acceleration), "LT⁻²");
+    assert_eq!(
+        format!("{}", Unit::from_powers([12, 0, 0, 0, 0, 0, 0])),
+        "L¹²"
+    );
+    assert_eq!(
+        format!("{}", Unit::from_powers([0, -128, 0, 0, 0, 0, 0])),
+        "M⁻¹²⁸"
+    );
+}
+
+#[test]
+fn compare_units() {
+    use crate::miscs::units::{BaseUnit, UnitPrefix};
+
+    let metre: Unit = Unit::from_powers([1, 0, 0, 0, 0, 0, 0]);
+    let mut kilometre: Unit = Unit::from_powers([1, 0, 0, 0, 0, 0, 0]);
+    kilometre.set_prefix(BaseUnit::Length, UnitPrefix::Kilo);
+    let kilogram: Unit = Unit::from_powers([0, 1, 0, 0, 0, 0, 0]);
+
+    assert!(metre.same_dimension(&kilometre));
+    assert!(metre != kilometre);
+    assert!(metre == Unit::from_powers([1, 0, 0, 0, 0, 0, 0]));
+    assert!(!metre.same_dimension(&kilogram));
+}
+
+#[test]
+fn base_factor() {
+    use crate::miscs::units::{BaseUnit, UnitPrefix};
+
+    let mut square_kilometre: Unit = Unit::from_powers([2, 0, 0, 0, 0, 0, 0]);
+    square_kilometre.set_prefix(BaseUnit::Length, UnitPrefix::Kilo);
+    assert_eq!(square_kilometre.to_base_factor(), 1e6);
+
+    let mut per_millisecond: Unit = Unit::from_powers([0, 0, -1, 0, 0, 0, 0]);
+    per_millisecond.set_prefix(BaseUnit::Time, UnitPrefix::Milli);
+    assert_eq!(per_millisecond.to_base_factor(), 1e3);
+
+    let mut per_cubic_kilometre: Unit = Unit::from_powers([-3, 0, 0, 0, 0, 0, 0]);
+    per_cubic_kilometre.set_prefix(BaseUnit::Length, UnitPrefix::Kilo);
+    assert_eq!(per_cubic_kilometre.to_base_factor(), 1e-9);
+
+    assert_eq!(
+        Unit::from_powers([1, 0, 0, 0, 0, 0, 0]).to_base_factor(),
+        1.0
+    );
+}
+
+#[test]
+fn derived_units() {
+    assert_eq!(Unit::newton().powers(), [1, 1, -2, 0, 0, 0, 0]);
+    assert_eq!(Unit::joule().powers(), [2, 1, -2, 0, 0, 0, 0]);
+    assert!((Unit::newton() * Unit::from_powers([1, 0, 0, 0, 0, 0, 0])) == Unit::joule());
+    assert!((Unit::joule() / Unit::from_powers([0, 0, 1, 0, 0, 0, 0])) == Unit::watt());
+}
+
+#[test]
+fn unit_pow() {
+    let length: Unit = Unit::from_powers([1, 0, 0, 0, 0, 0, 0]);
+
+    assert_eq!(length.pow(3).powers(), [3, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(Unit::hertz().pow(-1).powers(), [0, 0, 1, 0, 0, 0, 0]);
+    assert_eq!(Unit::newton().pow(2).powers(), [2, 2, -4, 0, 0, 0, 0]);
+}
+
+#[test]
+#[should_panic]
+fn unit_pow_overflow() {
+    Unit::from_powers([100, 0, 0, 0, 0, 0, 0]).pow(2);
+}
+
+#[test]
+fn clone_unit() {
+    use crate::miscs::units::{BaseUnit, DimensionalUnit, UnitPrefix};
+
+    let unit: Unit = Unit::new_from_raw(
+        DimensionalUnit::new(BaseUnit::Length, UnitPrefix::Kilo, 1),
+        DimensionalUnit::new(BaseUnit::Mass, UnitPrefix::None, 0),
+        DimensionalUnit::new(BaseUnit::Time, UnitPrefix::None, -1),
+        DimensionalUnit::new(BaseUnit::ElectricCurrent, UnitPrefix::None, 0),
+        DimensionalUnit::new(BaseUnit::Temperature, UnitPrefix::None, 0),
+        DimensionalUnit::new(BaseUnit::AmountOfSubstance, UnitPrefix::None, 0),
+        DimensionalUnit::new(BaseUnit::LuminousIntensity, UnitPrefix::None, 0),
+    );
+    #[allow(clippy::clone_on_copy)]
+    let cloned: Unit = unit.clone();
+    let copied: Unit = unit;
+
+    assert!(cloned == unit);
+    assert!(copied == unit);
+    assert_eq!(format!("{}", cloned), "LkT⁻¹");
+}
+
+#[test]
+fn parse_unit() {
+    use crate::miscs::units::ParseUnitError;
+
+    let acceleration: Unit = "L T^-2".parse().unwrap();
+    assert_eq!(acceleration.powers(), [1, 0, -2, 0, 0, 0, 0]);
+
+    let round_trip: Unit = format!("{}", acceleration).parse().unwrap();
+    assert!(round_trip == acceleration);
+
+    let energy: Unit = "M L^2 T^-2".parse().unwrap();
+    assert!(energy == Unit::joule());
+    assert!(format!("{}", Unit::farad()).parse::<Unit>().unwrap() == Unit::farad());
+
+    assert_eq!(
+        "L X".parse::<Unit>().err(),
+        Some(ParseUnitError::UnknownSymbol('X'))
+    );
+    assert_eq!(
+        "L^".parse::<Unit>().err(),
+        Some(ParseUnitError::InvalidPower("".to_string()))
+    );
+    assert_eq!(
+        "L^-".parse::<Unit>().err(),
+        Some(ParseUnitError::InvalidPower("-".to_string()))
+    );
+}
+
+#[test]
+fn prefix_factor() {
+    use crate::miscs::units::UnitPrefix;
+
+    assert_eq!(UnitPrefix::Kilo.factor(), 1000.0);
+    assert_eq!(UnitPrefix::Milli.factor(), 0.001);
+    assert_eq!(UnitPrefix::None.factor(), 1.0);
+    assert_eq!(UnitPrefix::Kilo.factor() / UnitPrefix::Centi.factor(), 1e5);
+}
+
+#[test]
+fn normalize_prefix() {
+    use crate::miscs::units::{normalize_prefix, BaseUnit, UnitPrefix};
+
+    let second: Unit = Unit::from_powers([0, 0, 1, 0, 0, 0, 0]);
+    let mut millisecond: Unit = second;
+    millisecond.set_prefix(BaseUnit::Time, UnitPrefix::Milli);
+
+    let (value, unit) = normalize_prefix(0.0015, &second);
+    assert!((value - 1.5).abs() < 1e-12);
+    assert_eq!(unit, millisecond);
+
+    let metre: Unit = Unit::from_powers([1, 0, 0, 0, 0, 0, 0]);
+    let mut kilometre: Unit = metre;
+    kilometre.set_prefix(BaseUnit::Length, UnitPrefix::Kilo);
+
+    let (value, unit) = normalize_prefix(1500.0, &metre);
+    assert!((value - 1.5).abs() < 1e-12);
+    assert_eq!(unit, kilometre);
+
+    let (value, unit) = normalize_prefix(2_500_000.0, &kilometre);
+    assert!((value - 2.5).abs() < 1e-9);
+    assert_eq!(unit.to_base_factor(), 1e9);
+
+    // Per-second rates pick the prefix on the inverse power: 1500 s⁻¹ = 1.5 ms⁻¹.
+    let (value, unit) = normalize_prefix(1500.0, &Unit::hertz());
+    assert!((value - 1.5).abs() < 1e-12);
+    assert_eq!(unit.to_base_factor(), 1e3);
+
+    assert_eq!(normalize_prefix(0.0, &metre).0, 0.0);
+    assert_eq!(normalize_prefix(42.0, &Unit::new()), (42.0, Unit::new()));
+}
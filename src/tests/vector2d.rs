@@ -0,0 +1,15 @@
+use crate::vectors::vector2d::Vector2D;
+
+#[test]
+fn angle_between_vectors() {
+    let a: Vector2D<f64> = Vector2D::new(1.0, 0.0);
+    let b: Vector2D<f64> = Vector2D::new(0.0, 2.0);
+
+    assert!((a.angle(&b).get_deg() - 90.0).abs() < 1e-9);
+    assert!((a.angle(&Vector2D::new(3.0, 3.0)).get_deg() - 45.0).abs() < 1e-9);
+
+    let v: Vector2D<f64> = Vector2D::new(0.1, 0.1);
+
+    assert!(v.angle(&Vector2D::new(0.3, 0.3)).get_rad().abs() < 1e-7);
+    assert!((v.angle(&Vector2D::new(-0.3, -0.3)).get_rad() - std::f64::consts::PI).abs() < 1e-7);
+}
@@ -0,0 +1,371 @@
+use crate::vectors::vector3d::Vector3D;
+
+#[test]
+fn outer_product() {
+    let a: Vector3D<i32> = Vector3D::new(1, 2, 3);
+    let b: Vector3D<i32> = Vector3D::new(4, 5, 6);
+
+    assert_eq!(a.outer(&b), [[4, 5, 6], [8, 10, 12], [12, 15, 18]]);
+}
+
+#[test]
+fn determinant() {
+    let i: Vector3D<i32> = Vector3D::new(1, 0, 0);
+    let j: Vector3D<i32> = Vector3D::new(0, 1, 0);
+    let k: Vector3D<i32> = Vector3D::new(0, 0, 1);
+
+    assert_eq!(Vector3D::determinant(&i, &j, &k), 1);
+    assert_eq!(Vector3D::determinant(&j, &i, &k), -1);
+
+    let a: Vector3D<i32> = Vector3D::new(1, 2, 0);
+    let b: Vector3D<i32> = Vector3D::new(3, -1, 0);
+    let c: Vector3D<i32> = Vector3D::new(5, 5, 0);
+
+    assert_eq!(Vector3D::determinant(&a, &b, &c), 0);
+}
+
+#[test]
+fn midpoint_and_centroid() {
+    let a: Vector3D<i32> = Vector3D::new(0, 2, 4);
+    let b: Vector3D<i32> = Vector3D::new(1, 4, -4);
+
+    assert_eq!(a.midpoint(&b).to_array(), [0.5, 3.0, 0.0]);
+
+    let triangle: [Vector3D<f64>; 3] = [
+        Vector3D::new(0.0, 0.0, 0.0),
+        Vector3D::new(1.0, 0.0, 0.0),
+        Vector3D::new(0.0, 1.0, 0.0),
+    ];
+    let centroid: Vector3D<f64> = Vector3D::centroid(&triangle).unwrap();
+
+    assert!((centroid.x - 1.0 / 3.0).abs() < 1e-12);
+    assert!((centroid.y - 1.0 / 3.0).abs() < 1e-12);
+    assert_eq!(centroid.z, 0.0);
+    assert!(Vector3D::centroid(&[]).is_none());
+}
+
+#[test]
+fn cylindrical_round_trip() {
+    let v: Vector3D<f64> = Vector3D::new(-1.0, 1.0, 2.5);
+    let (rho, phi, z) = v.to_cylindrical();
+
+    assert!((rho - 2f64.sqrt()).abs() < 1e-12);
+    assert!((phi.get_deg() - 135.0).abs() < 1e-9);
+    assert_eq!(z, 2.5);
+
+    let w: Vector3D<f64> = Vector3D::from_cylindrical(rho, phi, z);
+
+    assert!((w.x - v.x).abs() < 1e-12);
+    assert!((w.y - v.y).abs() < 1e-12);
+    assert_eq!(w.z, v.z);
+}
+
+#[test]
+fn angle_between_vectors() {
+    let a: Vector3D<f64> = Vector3D::new(1.0, 0.0, 0.0);
+    let b: Vector3D<f64> = Vector3D::new(1.0, 1.0, 0.0);
+
+    assert!((a.angle_deg(&b) - 45.0).abs() < 1e-9);
+    assert!((a.angle_rad(&b) - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+
+    let v: Vector3D<f64> = Vector3D::new(0.1, 0.1, 0.1);
+    let w: Vector3D<f64> = Vector3D::new(0.1, 0.1, 0.1) * 3.0;
+
+    assert_eq!(v.angle_rad(&w), 0.0);
+    assert!((v.angle_deg(&(Vector3D::default() - w)) - 180.0).abs() < 1e-9);
+}
+
+#[test]
+fn clamp_components() {
+    let v: Vector3D<i32> = Vector3D::new(-1, 5, 2);
+    let min: Vector3D<i32> = Vector3D::new(0, 0, 0);
+    let max: Vector3D<i32> = Vector3D::new(3, 3, 3);
+
+    assert_eq!(v.clamp(&min, &max).to_array(), [0, 3, 2]);
+
+    let inverted: Vector3D<i32> = Vector3D::new(5, 5, 5);
+    assert_eq!(v.clamp(&inverted, &max).to_array(), [5, 3, 5]);
+}
+
+#[test]
+fn polynomial_valued_vectors() {
+    use crate::polynomial::Polynomial;
+
+    // Quadratic Bézier with control points (0, 0, 0), (1, 2, 0) and (2, 0, 1):
+    // B(t) = (2t, 4t - 4t^2, t^2)
+    let curve: Vector3D<Polynomial<f64>> = Vector3D::new(
+        Polynomial::from_coefficients(vec![0.0, 2.0]),
+        Polynomial::from_coefficients(vec![0.0, 4.0, -4.0]),
+        Polynomial::from_coefficients(vec![0.0, 0.0, 1.0]),
+    );
+    let tangent: Vector3D<Polynomial<f64>> = Vector3D::new(
+        curve.x.derivative(),
+        curve.y.derivative(),
+        curve.z.derivative(),
+    );
+
+    let t: f64 = 0.25;
+    let at = |v: &Vector3D<Polynomial<f64>>| {
+        Vector3D::new(v.x.evaluate(t), v.y.evaluate(t), v.z.evaluate(t))
+    };
+
+    assert_eq!(at(&tangent).to_array(), [2.0, 2.0, 0.5]);
+
+    let speed_squared: Polynomial<f64> = tangent.dot(&tangent);
+    assert_eq!(speed_squared.evaluate(t), at(&tangent).dot(&at(&tangent)));
+
+    let binormal: Vector3D<Polynomial<f64>> = curve.cross(&tangent);
+    assert_eq!(
+        at(&binormal).to_array(),
+        at(&curve).cross(&at(&tangent)).to_array()
+    );
+}
+
+#[test]
+fn big_int_components() {
+    use num::BigInt;
+
+    let big: BigInt = BigInt::from(u64::MAX);
+    let a: Vector3D<BigInt> = Vector3D::new(big.clone(), BigInt::from(2), BigInt::from(3));
+    let b: Vector3D<BigInt> =
+        Vector3D::from_array([BigInt::from(1), big.clone(), BigInt::from(-1)]);
+
+    assert_eq!(a.dot(&b), big.clone() * 3 - 3);
+    assert_eq!(
+        a.magnitude_squared(),
+        big.clone() * big.clone() + BigInt::from(13)
+    );
+    assert_eq!(
+        a.cross(&b).to_array(),
+        [
+            BigInt::from(-2) - big.clone() * 3,
+            big.clone() + 3,
+            big.clone() * big.clone() - 2
+        ]
+    );
+
+    let scaled: Vector3D<BigInt> = (a.clone() + b) * BigInt::from(2) / BigInt::from(2);
+    assert_eq!(scaled.x, big + 1);
+}
+
+#[test]
+fn pretty_debug() {
+    let v: Vector3D<f64> = Vector3D::new(1.0, -2.5, 3.0);
+
+    assert_eq!(v.pretty_debug(), "Vector3D { x: 1.0, y: -2.5, z: 3.0 }");
+    assert_eq!(format!("{:?}", v), "(1.0, -2.5, 3.0)");
+    assert!(Vector3D::new("a", "b", "c")
+        .pretty_debug()
+        .contains("y: \"b\""));
+}
+
+#[test]
+fn magnitude_robust() {
+    let huge: Vector3D<f64> = Vector3D::new(3e200, 4e200, 12e200);
+    assert!(huge.magnitude().is_infinite());
+    assert!((huge.magnitude_robust() / 13e200 - 1.0).abs() < 1e-12);
+
+    let tiny: Vector3D<f64> = Vector3D::new(3e-200, 4e-200, 12e-200);
+    assert_eq!(tiny.magnitude(), 0.0);
+    assert!((tiny.magnitude_robust() / 13e-200 - 1.0).abs() < 1e-12);
+
+    assert_eq!(Vector3D::new(2, 3, 6).magnitude_robust(), 7.0);
+}
+
+#[test]
+fn dot_kahan() {
+    let a: Vector3D<f64> = Vector3D::new(1e16, 1.0, -1e16);
+    let b: Vector3D<f64> = Vector3D::new(1.0, 1.0, 1.0);
+
+    assert_eq!(a.dot(&b), 0.0);
+    assert_eq!(a.dot_kahan(&b), 1.0);
+    assert_eq!(
+        Vector3D::new(1, 2, 3).dot_kahan(&Vector3D::new(4, 5, 6)),
+        32.0
+    );
+}
+
+#[test]
+fn display_with_basis() {
+    let v: Vector3D<f64> = Vector3D::new(0.0, 1.0, -2.5);
+
+    assert_eq!(format!("{}", v), "j-2.5k");
+    assert_eq!(v.to_string_with_basis(["e1", "e2", "e3"]), "e2-2.5e3");
+    assert_eq!(
+        Vector3D::new(1, 0, 1).to_string_with_basis(["x̂", "ŷ", "ẑ"]),
+        "x̂+ẑ"
+    );
+}
+
+#[test]
+fn rotation_matrix_matches_rotate_around() {
+    use crate::angles::Angle;
+
+    let axis: Vector3D<f64> = Vector3D::new(1.0, 2.0, -2.0);
+    let angle: Angle = Angle::new_deg(73.0);
+    let matrix: [[f64; 3]; 3] = Vector3D::rotation_matrix(&axis, angle);
+
+    let basis: [Vector3D<f64>; 3] = [
+        Vector3D::new(1.0, 0.0, 0.0),
+        Vector3D::new(0.0, 1.0, 0.0),
+        Vector3D::new(0.0, 0.0, 1.0),
+    ];
+
+    for (column, e) in basis.iter().enumerate() {
+        let rotated: [f64; 3] = e.rotate_around(&axis, angle).to_array();
+
+        for row in 0..3 {
+            assert!((matrix[row][column] - rotated[row]).abs() < 1e-12);
+        }
+    }
+
+    let quarter: Vector3D<f64> =
+        basis[0].rotate_around(&Vector3D::new(0.0, 0.0, 5.0), Angle::RIGHT);
+    assert!(quarter.x.abs() < 1e-12 && (quarter.y - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn bounding_box() {
+    let points: [Vector3D<f64>; 4] = [
+        Vector3D::new(1.0, -2.0, 0.5),
+        Vector3D::new(-3.0, 4.0, 0.0),
+        Vector3D::new(2.5, 1.0, -1.0),
+        Vector3D::new(0.0, 0.0, 7.0),
+    ];
+    let (min, max) = Vector3D::bounds(&points).unwrap();
+
+    assert_eq!(min.to_array(), [-3.0, -2.0, -1.0]);
+    assert_eq!(max.to_array(), [2.5, 4.0, 7.0]);
+
+    let (min, max) = Vector3D::bounds(&points[..1]).unwrap();
+    assert_eq!(min.to_array(), max.to_array());
+    assert!(Vector3D::bounds(&[]).is_none());
+}
+
+#[test]
+fn slerp_between_directions() {
+    let a: Vector3D<f64> = Vector3D::new(1.0, 0.0, 0.0);
+    let b: Vector3D<f64> = Vector3D::new(0.0, 0.0, 1.0);
+    let mid: Vector3D<f64> = a.slerp(&b, 0.5);
+
+    assert!((mid.magnitude() - 1.0).abs() < 1e-12);
+    assert!((mid.angle_deg(&a) - 45.0).abs() < 1e-9);
+    assert!((mid.angle_deg(&b) - 45.0).abs() < 1e-9);
+
+    let third: Vector3D<f64> = a.slerp(&b, 1.0 / 3.0);
+    assert!((third.angle_deg(&a) - 30.0).abs() < 1e-9);
+    assert_eq!(a.slerp(&b, 0.0).to_array(), a.to_array());
+
+    let same: Vector3D<f64> = a.slerp(&a, 0.7);
+    assert_eq!(same.to_array(), a.to_array());
+    assert_eq!(a.lerp(&b, 0.25).to_array(), [0.75, 0.0, 0.25]);
+}
+
+#[test]
+fn rotate_by_euler_angles() {
+    use crate::angles::Angle;
+
+    let close = |v: Vector3D<f64>, expected: [f64; 3]| {
+        v.to_array()
+            .iter()
+            .zip(expected)
+            .all(|(a, b)| (a - b).abs() < 1e-12)
+    };
+    let x: Vector3D<f64> = Vector3D::new(1.0, 0.0, 0.0);
+    let z: Vector3D<f64> = Vector3D::new(0.0, 0.0, 1.0);
+
+    assert!(close(
+        x.rotate_euler(Angle::ZERO, Angle::ZERO, Angle::RIGHT),
+        [0.0, 1.0, 0.0]
+    ));
+    assert!(close(
+        x.rotate_euler(Angle::ZERO, Angle::RIGHT, Angle::ZERO),
+        [0.0, 0.0, -1.0]
+    ));
+
+    // Roll 90° sends z to -y, then yaw 90° sends -y to x.
+    assert!(close(
+        z.rotate_euler(Angle::RIGHT, Angle::ZERO, Angle::RIGHT),
+        [1.0, 0.0, 0.0]
+    ));
+
+    // Positive pitch tips x towards -z, so -45° raises the nose before yawing it onto y.
+    let half: f64 = std::f64::consts::FRAC_1_SQRT_2;
+    assert!(close(
+        x.rotate_euler(Angle::ZERO, Angle::new_deg(-45.0), Angle::RIGHT),
+        [0.0, half, half]
+    ));
+}
+
+#[test]
+fn dot_wide() {
+    let a: Vector3D<i32> = Vector3D::new(i32::MAX, i32::MAX - 1, i32::MIN);
+    let b: Vector3D<i32> = Vector3D::new(i32::MAX, i32::MAX, i32::MIN);
+
+    assert!(a.x.checked_mul(b.x).is_none());
+
+    let max: i128 = i32::MAX as i128;
+    let min: i128 = i32::MIN as i128;
+    assert_eq!(a.dot_wide(&b), max * max + (max - 1) * max + min * min);
+    assert!(a.dot_wide(&b) > i64::MAX as i128);
+
+    assert_eq!(
+        Vector3D::new(1u8, 2, 3).dot_wide(&Vector3D::new(4, 5, 6)),
+        32
+    );
+}
+
+#[test]
+fn ray_polynomials() {
+    use crate::polynomial::Polynomial;
+
+    let origin: Vector3D<f64> = Vector3D::new(1.0, -2.0, 0.5);
+    let direction: Vector3D<f64> = Vector3D::new(0.0, 3.0, -1.5);
+    let ray: [Polynomial<f64>; 3] = Vector3D::ray(&origin, &direction);
+
+    assert!(ray.iter().all(|p| p.degree() == 1));
+    assert_eq!(ray.clone().map(|p| p.evaluate(0.0)), origin.to_array());
+    assert_eq!(
+        ray.clone().map(|p| p.evaluate(1.0)),
+        (origin + direction).to_array()
+    );
+
+    // Hitting the unit sphere around the origin: |r(t)|^2 - 1 = 0 along the x axis.
+    let [x, y, z] = Vector3D::ray(
+        &Vector3D::new(-3.0, 0.0, 0.0),
+        &Vector3D::new(1.0, 0.0, 0.0),
+    );
+    let sphere: Polynomial<f64> =
+        x.clone() * x + y.clone() * y + z.clone() * z - Polynomial::from_coefficients(vec![1.0]);
+    assert_eq!(sphere.count_real_roots(0.0, 10.0), 2);
+}
+
+#[test]
+fn gram_schmidt_orthonormal_frame() {
+    let inputs: [Vector3D<f64>; 5] = [
+        Vector3D::new(1.0, 1.0, 0.0),
+        Vector3D::new(2.0, 2.0, 0.0),
+        Vector3D::new(1.0, 0.0, 1.0),
+        Vector3D::new(0.0, 0.0, 0.0),
+        Vector3D::new(0.3, -2.0, 5.0),
+    ];
+    let frame: Vec<Vector3D<f64>> = Vector3D::gram_schmidt(&inputs);
+
+    assert_eq!(frame.len(), 3);
+
+    for (i, a) in frame.iter().enumerate() {
+        assert!((a.magnitude() - 1.0).abs() < 1e-12);
+
+        for b in &frame[i + 1..] {
+            assert!(a.dot(b).abs() < 1e-12);
+        }
+    }
+
+    // The first output keeps the direction of the first input.
+    assert!(frame[0].angle_rad(&inputs[0]) < 1e-9);
+    assert!(Vector3D::gram_schmidt(&[]).is_empty());
+
+    let projected: Vector3D<f64> =
+        Vector3D::new(3.0, 4.0, 5.0).project_onto(&Vector3D::new(0.0, 2.0, 0.0));
+    assert_eq!(projected.to_array(), [0.0, 4.0, 0.0]);
+}
@@ -1,17 +1,118 @@
-pub trait Numeric {}
-
-impl Numeric for f64 {}
-impl Numeric for f32 {}
-impl Numeric for i64 {}
-impl Numeric for i32 {}
-impl Numeric for i16 {}
-impl Numeric for i8 {}
-impl Numeric for isize {}
-impl Numeric for u64 {}
-impl Numeric for u32 {}
-impl Numeric for u16 {}
-impl Numeric for u8 {}
-impl Numeric for usize {}
+use super::nums::{complex::Complex, quaternion::Quaternion};
+
+use num::{Complex as NumComplex, Float, Num, One, Zero};
+use std::ops::Neg;
+
+/// A scalar usable by the vector, angle and polynomial code.
+/// The additive and multiplicative identities come from `num::Zero` and `num::One`.
+///
+/// All primitive numeric types implement `Numeric`. To opt in your own scalar type,
+/// implement `num::Zero` and `num::One` for it and register it with
+/// [`impl_numeric!`](crate::impl_numeric), giving the conversion to `f64`:
+///
+/// ```rust
+/// use num::{One, Zero};
+/// use std::ops::{Add, Mul};
+/// use vectra::{impl_numeric, traits::Numeric};
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// struct Meters(f64);
+///
+/// impl Add for Meters {
+///     type Output = Self;
+///     fn add(self, rhs: Self) -> Self {
+///         Meters(self.0 + rhs.0)
+///     }
+/// }
+///
+/// impl Mul for Meters {
+///     type Output = Self;
+///     fn mul(self, rhs: Self) -> Self {
+///         Meters(self.0 * rhs.0)
+///     }
+/// }
+///
+/// impl Zero for Meters {
+///     fn zero() -> Self {
+///         Meters(0.0)
+///     }
+///     fn is_zero(&self) -> bool {
+///         self.0 == 0.0
+///     }
+/// }
+///
+/// impl One for Meters {
+///     fn one() -> Self {
+///         Meters(1.0)
+///     }
+/// }
+///
+/// impl_numeric!(Meters => |value: &Meters| value.0);
+///
+/// assert_eq!(Meters(2.5).to_f64(), 2.5);
+/// ```
+pub trait Numeric: Zero + One {
+    fn to_f64(&self) -> f64;
+}
+
+crate::impl_numeric!(f64, f32, i64, i32, i16, i8, isize, u64, u32, u16, u8, usize);
+
+/// Complex conjugation, which is the identity for real scalars.
+pub trait Conjugate {
+    fn conjugate(&self) -> Self;
+}
+
+macro_rules! impl_real_conjugate {
+    ($($t:ty),*) => {
+        $(
+            impl Conjugate for $t {
+                fn conjugate(&self) -> Self {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_real_conjugate!(f64, f32, i64, i32, i16, i8, isize, u64, u32, u16, u8, usize);
+
+impl<T> Conjugate for NumComplex<T>
+where
+    T: Clone + Num + Neg<Output = T>,
+{
+    fn conjugate(&self) -> Self {
+        self.conj()
+    }
+}
+
+impl<T> Conjugate for Complex<T>
+where
+    T: Clone + Neg<Output = T>,
+{
+    fn conjugate(&self) -> Self {
+        Complex::conjugate(self)
+    }
+}
+
+impl<T> Conjugate for Quaternion<T>
+where
+    T: Clone + Neg<Output = T>,
+{
+    fn conjugate(&self) -> Self {
+        Quaternion::conjugate(self)
+    }
+}
+
+/// Scalars with exact division, i.e. a field up to rounding.
+/// Polynomial division and remainders are only defined over these, since integer
+/// division would truncate the quotient's coefficients.
+pub trait Field: Num + Clone {}
+
+impl Field for f64 {}
+impl Field for f32 {}
+impl<T: Float> Field for NumComplex<T> {}
+impl<T: Float> Field for Complex<T> {}
+
 // pub trait Evaluate<T> {
 //     fn evaluate(&self, x: T) -> T;
 // }
@@ -0,0 +1,97 @@
+use super::vector3d::Vector3D;
+
+/// Evaluates the Bézier curve with the given control points at `t` using de Casteljau's algorithm,
+/// repeatedly interpolating between neighbouring points until one remains.
+/// - `t = 0.0` gives the first control point and `t = 1.0` the last.
+/// - Values of `t` outside `[0, 1]` extrapolate the curve.
+///
+/// # Panics
+///
+/// Panics if `control` is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use vectra::vectors::curves::bezier_point;
+/// use vectra::vectors::vector3d::Vector3D;
+///
+/// let control: [Vector3D<f64>; 3] = [
+///     Vector3D::new(0.0, 0.0, 0.0),
+///     Vector3D::new(1.0, 2.0, 0.0),
+///     Vector3D::new(2.0, 0.0, 0.0),
+/// ];
+///
+/// assert_eq!(bezier_point(&control, 0.5).to_array(), [1.0, 1.0, 0.0]);
+/// ```
+pub fn bezier_point(control: &[Vector3D<f64>], t: f64) -> Vector3D<f64> {
+    assert!(
+        !control.is_empty(),
+        "a Bézier curve needs at least one control point"
+    );
+
+    let mut points: Vec<Vector3D<f64>> = control.to_vec();
+
+    for len in (1..points.len()).rev() {
+        for i in 0..len {
+            points[i] = points[i].lerp(&points[i + 1], t);
+        }
+    }
+
+    points[0]
+}
+
+/// Evaluates a centripetal Catmull–Rom spline through `points`.
+/// - `t` runs from `0.0` to `points.len() - 1`, and the curve passes through `points[i]` at `t = i`.
+///   Values outside that range are clamped.
+/// - Each segment uses the neighbouring waypoints as tangent guides; the first and last points
+///   are duplicated to stand in for the missing neighbours at the ends.
+/// - Knots are spaced by the square root of the chord length (α = 0.5), which avoids the cusps
+///   and self-intersections of the uniform variant.
+///
+/// # Panics
+///
+/// Panics if `points` is empty.
+pub fn catmull_rom(points: &[Vector3D<f64>], t: f64) -> Vector3D<f64> {
+    assert!(!points.is_empty(), "a spline needs at least one waypoint");
+
+    let last: usize = points.len() - 1;
+    if last == 0 {
+        return points[0];
+    }
+
+    let t: f64 = t.clamp(0.0, last as f64);
+    let segment: usize = (t.floor() as usize).min(last - 1);
+    let u: f64 = t - segment as f64;
+
+    let p: [Vector3D<f64>; 4] = [
+        points[segment.saturating_sub(1)],
+        points[segment],
+        points[segment + 1],
+        points[(segment + 2).min(last)],
+    ];
+
+    let mut knots: [f64; 4] = [0.0; 4];
+    for i in 1..4 {
+        knots[i] = knots[i - 1] + (p[i] - p[i - 1]).magnitude().sqrt();
+    }
+
+    // Barry–Goldman pyramid of linear blends between knots.
+    let blend = |a: Vector3D<f64>, b: Vector3D<f64>, ta: f64, tb: f64, t: f64| {
+        if tb == ta {
+            a
+        } else {
+            a.lerp(&b, (t - ta) / (tb - ta))
+        }
+    };
+
+    let [t0, t1, t2, t3] = knots;
+    let t: f64 = t1 + u * (t2 - t1);
+
+    let a1: Vector3D<f64> = blend(p[0], p[1], t0, t1, t);
+    let a2: Vector3D<f64> = blend(p[1], p[2], t1, t2, t);
+    let a3: Vector3D<f64> = blend(p[2], p[3], t2, t3, t);
+    let b1: Vector3D<f64> = blend(a1, a2, t0, t2, t);
+    let b2: Vector3D<f64> = blend(a2, a3, t1, t3, t);
+
+    blend(b1, b2, t1, t2, t)
+}
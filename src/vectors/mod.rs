@@ -1,2 +1,3 @@
+pub mod curves;
 pub mod vector2d;
 pub mod vector3d;
@@ -88,6 +88,8 @@ impl<T> Vector2D<T> {
         self.dot(&other.normalize())
     }
 
+    /// Returns the angle between the two vectors in `[0, π]`.
+    /// The cosine is clamped to `[-1, 1]` so (anti)parallel vectors don't produce NaN.
     pub fn angle(&self, other: &Self) -> Angle
     where
         T: Copy + Into<f64> + Mul<Output = T> + Div<Output = T> + Add<Output = T>,
@@ -96,7 +98,16 @@ impl<T> Vector2D<T> {
         let magnitude_product = (self.magnitude_squared() * other.magnitude_squared())
             .into()
             .sqrt();
-        Angle::new_rad(dot_product.acos() / magnitude_product)
+        Angle::new_rad((dot_product / magnitude_product).clamp(-1.0, 1.0).acos())
+    }
+
+    /// Returns the direction of the vector as an angle from the positive x axis,
+    /// see [`Angle::from_vector2d`].
+    pub fn heading(&self) -> Angle
+    where
+        T: Copy + Into<f64>,
+    {
+        Angle::from_vector2d(self.x.into(), self.y.into())
     }
 }
 
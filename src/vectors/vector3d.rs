@@ -1,4 +1,5 @@
 use super::super::angles::Angle;
+use super::super::polynomial::Polynomial;
 
 use num::{One, Zero};
 use std::{
@@ -6,6 +7,7 @@ use std::{
     ops::{Add, Div, Mul, Neg, Sub},
 };
 
+#[derive(Clone, Copy)]
 pub struct Vector3D<T> {
     pub x: T,
     pub y: T,
@@ -27,29 +29,54 @@ impl<T> Vector3D<T> {
         Self { x, y, z }
     }
 
-    pub fn from_array(array: [T; 3]) -> Self
+    pub fn from_array(array: [T; 3]) -> Self {
+        let [x, y, z] = array;
+        Self { x, y, z }
+    }
+
+    pub fn to_array(&self) -> [T; 3]
     where
-        T: Copy,
+        T: Clone,
     {
-        Self {
-            x: array[0],
-            y: array[1],
-            z: array[2],
-        }
+        [self.x.clone(), self.y.clone(), self.z.clone()]
     }
 
-    pub fn to_array(&self) -> [T; 3]
+    /// Returns the components converted to `f64`, e.g. to promote an integer vector for geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::vectors::vector3d::Vector3D;
+    ///
+    /// let v: Vector3D<i32> = Vector3D::new(3, -4, 12);
+    ///
+    /// assert_eq!(v.to_f64_array(), [3.0, -4.0, 12.0]);
+    /// assert_eq!(Vector3D::from_array(v.to_f64_array()).magnitude(), 13.0);
+    /// ```
+    pub fn to_f64_array(&self) -> [f64; 3]
     where
-        T: Copy,
+        T: Copy + Into<f64>,
     {
-        [self.x, self.y, self.z]
+        [self.x.into(), self.y.into(), self.z.into()]
     }
 
     pub fn magnitude_squared(&self) -> T
     where
-        T: Copy + Mul<Output = T> + Add<Output = T>,
+        T: Clone + Mul<Output = T> + Add<Output = T>,
     {
-        self.x * self.x + self.y * self.y + self.z * self.z
+        self.dot(self)
+    }
+
+    /// Returns a labeled debug representation, `Vector3D { x: .., y: .., z: .. }`.
+    /// Unlike the `Debug` impl, the components are named.
+    pub fn pretty_debug(&self) -> String
+    where
+        T: Debug,
+    {
+        format!(
+            "Vector3D {{ x: {:?}, y: {:?}, z: {:?} }}",
+            self.x, self.y, self.z
+        )
     }
 
     pub fn magnitude(&self) -> f64
@@ -59,6 +86,15 @@ impl<T> Vector3D<T> {
         self.magnitude_squared().into().sqrt()
     }
 
+    /// Returns the magnitude using nested `hypot`, which neither overflows for huge components
+    /// nor underflows for tiny ones, unlike [`Vector3D::magnitude`].
+    pub fn magnitude_robust(&self) -> f64
+    where
+        T: Copy + Into<f64>,
+    {
+        self.x.into().hypot(self.y.into()).hypot(self.z.into())
+    }
+
     pub fn normalize(&self) -> Self
     where
         T: Copy + Into<f64> + From<f64> + Div<Output = T> + Add<Output = T> + Mul<Output = T>,
@@ -73,25 +109,69 @@ impl<T> Vector3D<T> {
 
     pub fn dot(&self, other: &Self) -> T
     where
-        T: Copy + Mul<Output = T> + Add<Output = T>,
+        T: Clone + Mul<Output = T> + Add<Output = T>,
     {
-        self.x * other.x + self.y * other.y + self.z * other.z
+        self.x.clone() * other.x.clone()
+            + self.y.clone() * other.y.clone()
+            + self.z.clone() * other.z.clone()
+    }
+
+    /// Returns the dot product accumulated in `f64` with compensated summation
+    /// (the Kahan–Babuška/Neumaier variant), so cancellation between large terms does not lose small ones.
+    pub fn dot_kahan(&self, other: &Self) -> f64
+    where
+        T: Copy + Into<f64>,
+    {
+        let products: [f64; 3] = [
+            self.x.into() * other.x.into(),
+            self.y.into() * other.y.into(),
+            self.z.into() * other.z.into(),
+        ];
+
+        let mut sum: f64 = 0.0;
+        let mut compensation: f64 = 0.0;
+
+        for product in products {
+            let total: f64 = sum + product;
+
+            if sum.abs() >= product.abs() {
+                compensation += (sum - total) + product;
+            } else {
+                compensation += (product - total) + sum;
+            }
+
+            sum = total;
+        }
+
+        sum + compensation
+    }
+
+    /// Returns the dot product accumulated in `i128`, which is exact for components up to 32 bits
+    /// wide (`i32`, `u32` and narrower), where [`Vector3D::dot`] would overflow.
+    /// Three products of full-range 32-bit components can exceed `i64`, hence the wider result.
+    pub fn dot_wide(&self, other: &Self) -> i128
+    where
+        T: Copy + Into<i128>,
+    {
+        self.x.into() * other.x.into()
+            + self.y.into() * other.y.into()
+            + self.z.into() * other.z.into()
     }
 
     pub fn cross(&self, other: &Self) -> Self
     where
-        T: Copy + Mul<Output = T> + Sub<Output = T>,
+        T: Clone + Mul<Output = T> + Sub<Output = T>,
     {
         Self {
-            x: self.y * other.z - self.z * other.y,
-            y: self.z * other.x - self.x * other.z,
-            z: self.x * other.y - self.y * other.x,
+            x: self.y.clone() * other.z.clone() - self.z.clone() * other.y.clone(),
+            y: self.z.clone() * other.x.clone() - self.x.clone() * other.z.clone(),
+            z: self.x.clone() * other.y.clone() - self.y.clone() * other.x.clone(),
         }
     }
 
     pub fn pow2(&self) -> T
     where
-        T: Copy + Mul<Output = T> + Add<Output = T>,
+        T: Clone + Mul<Output = T> + Add<Output = T>,
     {
         self.magnitude_squared()
     }
@@ -103,6 +183,8 @@ impl<T> Vector3D<T> {
         self.dot(&other.normalize())
     }
 
+    /// Returns the angle between the two vectors in `[0, π]`.
+    /// The cosine is clamped to `[-1, 1]` so (anti)parallel vectors don't produce NaN.
     pub fn angle(&self, other: &Self) -> Angle
     where
         T: Copy + Into<f64> + Mul<Output = T> + Div<Output = T> + Add<Output = T>,
@@ -111,7 +193,228 @@ impl<T> Vector3D<T> {
         let magnitude_product = (self.magnitude_squared() * other.magnitude_squared())
             .into()
             .sqrt();
-        Angle::new_rad(dot_product.acos() / magnitude_product)
+        Angle::new_rad((dot_product / magnitude_product).clamp(-1.0, 1.0).acos())
+    }
+
+    /// Returns the outer product `self ⊗ other`, where element `[i][j]` is `self[i] * other[j]`.
+    pub fn outer(&self, other: &Vector3D<T>) -> [[T; 3]; 3]
+    where
+        T: Clone + Mul<Output = T>,
+    {
+        let lhs: [T; 3] = self.to_array();
+        let rhs: [T; 3] = other.to_array();
+
+        lhs.map(|l| rhs.clone().map(|r| l.clone() * r))
+    }
+
+    /// Returns the determinant of the 3×3 matrix with columns `a`, `b` and `c`,
+    /// computed as the scalar triple product `a · (b × c)`.
+    pub fn determinant(a: &Vector3D<T>, b: &Vector3D<T>, c: &Vector3D<T>) -> T
+    where
+        T: Clone + Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        a.dot(&b.cross(c))
+    }
+
+    pub fn midpoint(&self, other: &Vector3D<T>) -> Vector3D<f64>
+    where
+        T: Copy + Into<f64>,
+    {
+        Vector3D {
+            x: (self.x.into() + other.x.into()) / 2.0,
+            y: (self.y.into() + other.y.into()) / 2.0,
+            z: (self.z.into() + other.z.into()) / 2.0,
+        }
+    }
+
+    /// Clamps each component independently between the corresponding components of `min` and `max`.
+    /// Unlike `f64::clamp` this never panics: if `min > max` on some axis, components below `min`
+    /// become `min` and all others become `max`.
+    pub fn clamp(&self, min: &Vector3D<T>, max: &Vector3D<T>) -> Vector3D<T>
+    where
+        T: PartialOrd + Copy,
+    {
+        let clamp = |value: T, lo: T, hi: T| -> T {
+            if value < lo {
+                lo
+            } else if value > hi {
+                hi
+            } else {
+                value
+            }
+        };
+
+        Vector3D {
+            x: clamp(self.x, min.x, max.x),
+            y: clamp(self.y, min.y, max.y),
+            z: clamp(self.z, min.z, max.z),
+        }
+    }
+
+    /// Converts to cylindrical coordinates `(ρ, φ, z)`.
+    /// - `ρ` is the distance from the z-axis.
+    /// - `φ` is the azimuth measured from the +x axis towards the +y axis, in `(-π, π]`.
+    pub fn to_cylindrical(&self) -> (f64, Angle, f64)
+    where
+        T: Copy + Into<f64>,
+    {
+        let (x, y): (f64, f64) = (self.x.into(), self.y.into());
+
+        (x.hypot(y), Angle::from_atan2(y, x), self.z.into())
+    }
+}
+
+impl Vector3D<f64> {
+    pub fn angle_rad(&self, other: &Vector3D<f64>) -> f64 {
+        self.angle(other).get_rad()
+    }
+
+    pub fn angle_deg(&self, other: &Vector3D<f64>) -> f64 {
+        self.angle(other).get_deg()
+    }
+
+    /// Creates a vector from cylindrical coordinates `(ρ, φ, z)`,
+    /// using the same azimuth convention as [`Vector3D::to_cylindrical`].
+    pub fn from_cylindrical(rho: f64, phi: Angle, z: f64) -> Vector3D<f64> {
+        Vector3D::new(rho * phi.cos(), rho * phi.sin(), z)
+    }
+
+    /// Linearly interpolates between the vectors, giving `self` at `t = 0.0` and `other` at `t = 1.0`.
+    pub fn lerp(&self, other: &Vector3D<f64>, t: f64) -> Vector3D<f64> {
+        *self + (*other - *self) * t
+    }
+
+    /// Spherically interpolates along the great circle between two (near-)unit vectors,
+    /// so the angular speed is constant in `t`.
+    /// - Falls back to [`Vector3D::lerp`] when the vectors are nearly parallel, where `sin θ` vanishes.
+    /// - Antiparallel vectors have no unique great circle and also take the `lerp` path.
+    pub fn slerp(&self, other: &Vector3D<f64>, t: f64) -> Vector3D<f64> {
+        let theta: f64 = self.angle(other).get_rad();
+        let sin_theta: f64 = theta.sin();
+
+        if sin_theta.abs() < 1e-9 {
+            return self.lerp(other, t);
+        }
+
+        *self * (((1.0 - t) * theta).sin() / sin_theta) + *other * ((t * theta).sin() / sin_theta)
+    }
+
+    /// Rotates the vector by `angle` around `axis` (right-hand rule) using Rodrigues' formula.
+    /// The axis does not need to be normalized.
+    pub fn rotate_around(&self, axis: &Vector3D<f64>, angle: Angle) -> Vector3D<f64> {
+        let k: Vector3D<f64> = axis.normalize();
+        let (sin, cos): (f64, f64) = (angle.sin(), angle.cos());
+
+        *self * cos + k.cross(self) * sin + k * (k.dot(self) * (1.0 - cos))
+    }
+
+    /// Rotates the vector by aerospace Euler angles using the intrinsic Z-Y'-X'' (yaw, pitch, roll) order,
+    /// i.e. `v' = Rz(yaw) · Ry(pitch) · Rx(roll) · v` about the fixed x, y and z axes.
+    /// - Positive angles follow the right-hand rule about each axis.
+    /// - At `pitch = ±90°` roll and yaw act about the same axis (gimbal lock), so different
+    ///   angle triples give the same rotation and cannot be recovered uniquely.
+    pub fn rotate_euler(&self, roll: Angle, pitch: Angle, yaw: Angle) -> Vector3D<f64> {
+        self.rotate_around(&Vector3D::new(1.0, 0.0, 0.0), roll)
+            .rotate_around(&Vector3D::new(0.0, 1.0, 0.0), pitch)
+            .rotate_around(&Vector3D::new(0.0, 0.0, 1.0), yaw)
+    }
+
+    /// Returns the 3×3 matrix (row-major) of the rotation by `angle` around `axis`,
+    /// so that `matrix · v` equals [`Vector3D::rotate_around`]. The axis is normalized first.
+    pub fn rotation_matrix(axis: &Vector3D<f64>, angle: Angle) -> [[f64; 3]; 3] {
+        let Vector3D { x, y, z } = axis.normalize();
+        let (sin, cos): (f64, f64) = (angle.sin(), angle.cos());
+        let t: f64 = 1.0 - cos;
+
+        [
+            [t * x * x + cos, t * x * y - sin * z, t * x * z + sin * y],
+            [t * x * y + sin * z, t * y * y + cos, t * y * z - sin * x],
+            [t * x * z - sin * y, t * y * z + sin * x, t * z * z + cos],
+        ]
+    }
+
+    /// Returns the arithmetic mean of the points, or `None` if the slice is empty.
+    pub fn centroid(points: &[Vector3D<f64>]) -> Option<Vector3D<f64>> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let count: f64 = points.len() as f64;
+        let sum: Vector3D<f64> = points.iter().fold(Vector3D::default(), |acc, point| {
+            Vector3D::new(acc.x + point.x, acc.y + point.y, acc.z + point.z)
+        });
+
+        Some(sum / count)
+    }
+
+    /// Returns the parametric line `origin + t · direction` as one degree-1 polynomial in `t`
+    /// per component, ready to be substituted into polynomial surfaces for intersection tests.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::vectors::vector3d::Vector3D;
+    ///
+    /// let [x, y, z] = Vector3D::ray(&Vector3D::new(1.0, 0.0, 0.0), &Vector3D::new(0.0, 2.0, 0.0));
+    ///
+    /// assert_eq!([x.evaluate(0.5), y.evaluate(0.5), z.evaluate(0.5)], [1.0, 1.0, 0.0]);
+    /// ```
+    pub fn ray(origin: &Vector3D<f64>, direction: &Vector3D<f64>) -> [Polynomial<f64>; 3] {
+        let (o, d): ([f64; 3], [f64; 3]) = (origin.to_array(), direction.to_array());
+
+        [0, 1, 2].map(|i| Polynomial::from_coefficients(vec![o[i], d[i]]))
+    }
+
+    /// Returns the projection of `self` onto the direction of `onto`, i.e. `(self·onto / onto·onto) · onto`.
+    /// Projecting onto the zero vector yields NaN components.
+    pub fn project_onto(&self, onto: &Vector3D<f64>) -> Vector3D<f64> {
+        *onto * (self.dot(onto) / onto.dot(onto))
+    }
+
+    /// Builds an orthonormal set from the inputs with (modified) Gram–Schmidt, in input order.
+    /// Vectors that are linearly dependent on the ones already kept, including zero vectors,
+    /// are skipped, so at most three vectors are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::vectors::vector3d::Vector3D;
+    ///
+    /// let frame = Vector3D::gram_schmidt(&[
+    ///     Vector3D::new(2.0, 0.0, 0.0),
+    ///     Vector3D::new(4.0, 0.0, 0.0),
+    ///     Vector3D::new(1.0, 3.0, 0.0),
+    /// ]);
+    ///
+    /// assert_eq!(frame.len(), 2);
+    /// assert_eq!(frame[1].to_array(), [0.0, 1.0, 0.0]);
+    /// ```
+    pub fn gram_schmidt(vectors: &[Vector3D<f64>]) -> Vec<Vector3D<f64>> {
+        let mut basis: Vec<Vector3D<f64>> = Vec::with_capacity(3);
+
+        for v in vectors {
+            let residual: Vector3D<f64> = basis.iter().fold(*v, |acc, e| acc - acc.project_onto(e));
+            let length: f64 = residual.magnitude();
+
+            if length > 1e-10 * v.magnitude() && basis.len() < 3 {
+                basis.push(residual / length);
+            }
+        }
+
+        basis
+    }
+
+    /// Returns the axis-aligned bounding box `(min, max)` of the points as component-wise
+    /// minimum and maximum corners, or `None` if the slice is empty.
+    pub fn bounds(points: &[Vector3D<f64>]) -> Option<(Vector3D<f64>, Vector3D<f64>)> {
+        let first: Vector3D<f64> = *points.first()?;
+
+        Some(points[1..].iter().fold((first, first), |(min, max), p| {
+            (
+                Vector3D::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)),
+                Vector3D::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z)),
+            )
+        }))
     }
 }
 
@@ -147,14 +450,14 @@ where
 
 impl<T> Mul<T> for Vector3D<T>
 where
-    T: Mul<Output = T> + Copy,
+    T: Mul<Output = T> + Clone,
 {
     type Output = Self;
 
     fn mul(self, scalar: T) -> Self {
         Self {
-            x: self.x * scalar,
-            y: self.y * scalar,
+            x: self.x * scalar.clone(),
+            y: self.y * scalar.clone(),
             z: self.z * scalar,
         }
     }
@@ -162,14 +465,14 @@ where
 
 impl<T> Div<T> for Vector3D<T>
 where
-    T: Div<Output = T> + Copy,
+    T: Div<Output = T> + Clone,
 {
     type Output = Self;
 
     fn div(self, scalar: T) -> Self {
         Self {
-            x: self.x / scalar,
-            y: self.y / scalar,
+            x: self.x / scalar.clone(),
+            y: self.y / scalar.clone(),
             z: self.z / scalar,
         }
     }
@@ -189,49 +492,70 @@ where
     T: Display + Zero + One + Neg<Output = T> + Copy + PartialEq<T>,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", pretty_print(self.x, self.y, self.z))
+        write!(f, "{}", self.to_string_with_basis(["i", "j", "k"]))
+    }
+}
+
+impl<T> Vector3D<T>
+where
+    T: Display + Zero + One + Neg<Output = T> + Copy + PartialEq<T>,
+{
+    /// Formats the vector like `Display`, using `labels` for the basis vectors instead of `i`, `j` and `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vectra::vectors::vector3d::Vector3D;
+    ///
+    /// let v: Vector3D<i32> = Vector3D::new(2, -1, 1);
+    ///
+    /// assert_eq!(v.to_string_with_basis(["x", "y", "z"]), "2x-y+z");
+    /// assert_eq!(v.to_string(), "2i-j+k");
+    /// ```
+    pub fn to_string_with_basis(&self, labels: [&str; 3]) -> String {
+        pretty_print(self.x, self.y, self.z, labels)
     }
 }
 
 #[inline]
-fn pretty_print<T>(x: T, y: T, z: T) -> String
+fn pretty_print<T>(x: T, y: T, z: T, [i, j, k]: [&str; 3]) -> String
 where
     T: Display + Zero + One + Neg<Output = T> + Copy + PartialEq<T>,
 {
     let fx: String = if x == T::one() {
-        "i".to_string()
+        i.to_string()
     } else if x == -T::one() {
-        "-i".to_string()
+        format!("-{}", i)
     } else if x != T::zero() {
-        format!("{}i", x)
+        format!("{}{}", x, i)
     } else {
         "".to_string()
     };
 
     let fy: String = if y == T::one() {
         if x != T::zero() {
-            "+j".to_string()
+            format!("+{}", j)
         } else {
-            "j".to_string()
+            j.to_string()
         }
     } else if y == -T::one() {
-        "-j".to_string()
+        format!("-{}", j)
     } else if y != T::zero() {
-        format!("{:+}j", y)
+        format!("{:+}{}", y, j)
     } else {
         "".to_string()
     };
 
     let fz: String = if z == T::one() {
         if x != T::zero() || y != T::zero() {
-            "+k".to_string()
+            format!("+{}", k)
         } else {
-            "k".to_string()
+            k.to_string()
         }
     } else if z == -T::one() {
-        "-k".to_string()
+        format!("-{}", k)
     } else if z != T::zero() {
-        format!("{:+}k", z)
+        format!("{:+}{}", z, k)
     } else {
         "".to_string()
     };